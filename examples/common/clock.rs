@@ -0,0 +1,240 @@
+// Generated from ActorSimulation DSL
+// Subsystem: virtual clock
+//
+// Shared by `ractor_pipeline`, `ractor_pubsub`, `ractor_loadbalanced`, and
+// `ractor_burst`. Each crate's `src/clock.rs` is a `#[path]` shim onto this
+// file rather than a copy, so there's one implementation to keep in sync.
+// `ractor_burst`'s periodic-batch timer (`schedule_repeating`) and its
+// `EventSink` timestamps (`VirtualClock::now()`, a `Duration`) are both
+// already covered by the API below; it used to carry its own nanosecond
+// `SimClock`/`Scheduler` pair instead of sharing this one.
+//
+// Generated actors no longer spawn a free-running `tokio::time::interval`
+// directly. Instead `pre_start` registers its periodic send with a shared
+// `VirtualClock`, so a whole simulation can be driven either by a
+// `RealTimeDriver` (wall-clock sleeps, for production) or a `VirtualDriver`
+// (jumps straight to the next event, for instant, reproducible runs).
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Identifies a scheduled event so it can be canceled with `VirtualClock::cancel`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScheduleId(u64);
+
+type ScheduledAction = Box<dyn FnMut() + Send>;
+
+struct ScheduledEvent {
+    fire_at: Duration,
+    seq: u64,
+    id: ScheduleId,
+    period: Option<Duration>,
+    action: ScheduledAction,
+}
+
+impl PartialEq for ScheduledEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.fire_at == other.fire_at && self.seq == other.seq
+    }
+}
+
+impl Eq for ScheduledEvent {}
+
+impl PartialOrd for ScheduledEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the comparison is reversed to pop
+        // the earliest `fire_at` first; ties break by insertion order
+        // (`seq`) so simulations stay deterministic across runs.
+        other
+            .fire_at
+            .cmp(&self.fire_at)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+struct ClockState {
+    now: Duration,
+    next_seq: u64,
+    next_id: u64,
+    events: BinaryHeap<ScheduledEvent>,
+    canceled: HashSet<ScheduleId>,
+}
+
+/// A deterministic, schedulable clock shared by every actor in a generated
+/// simulation. Holds the current simulated instant and a min-heap of
+/// pending events keyed by `(fire_at, seq)`.
+#[derive(Clone)]
+pub struct VirtualClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ClockState {
+                now: Duration::ZERO,
+                next_seq: 0,
+                next_id: 0,
+                events: BinaryHeap::new(),
+                canceled: HashSet::new(),
+            })),
+        }
+    }
+
+    /// The current simulated instant.
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now
+    }
+
+    /// Schedule `f` to run once after `delay` of simulated time.
+    pub fn schedule_once<F>(&self, delay: Duration, f: F) -> ScheduleId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.schedule(delay, None, f)
+    }
+
+    /// Schedule `f` to run every `period`, first firing after one `period`.
+    pub fn schedule_repeating<F>(&self, period: Duration, f: F) -> ScheduleId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.schedule(period, Some(period), f)
+    }
+
+    fn schedule<F>(&self, delay: Duration, period: Option<Duration>, f: F) -> ScheduleId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        let id = ScheduleId(state.next_id);
+        state.next_id += 1;
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        let fire_at = state.now + delay;
+        state.events.push(ScheduledEvent {
+            fire_at,
+            seq,
+            id,
+            period,
+            action: Box::new(f),
+        });
+        id
+    }
+
+    /// Cancel a previously scheduled event. A no-op if it already fired or
+    /// was already canceled.
+    pub fn cancel(&self, id: ScheduleId) {
+        self.state.lock().unwrap().canceled.insert(id);
+    }
+
+    /// The simulated time of the next pending event, if any.
+    fn next_fire_at(&self) -> Option<Duration> {
+        self.state.lock().unwrap().events.peek().map(|e| e.fire_at)
+    }
+
+    /// Pop and fire the earliest pending event, advancing `now` to its
+    /// `fire_at`. Returns `false` if there are no more events.
+    fn fire_next(&self) -> bool {
+        let (mut action, id, period) = {
+            let mut state = self.state.lock().unwrap();
+            let event = match state.events.pop() {
+                Some(event) => event,
+                None => return false,
+            };
+            state.now = event.fire_at;
+            if state.canceled.remove(&event.id) {
+                return true;
+            }
+            (event.action, event.id, event.period)
+        };
+        action();
+        if let Some(period) = period {
+            let mut state = self.state.lock().unwrap();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            let fire_at = state.now + period;
+            state.events.push(ScheduledEvent {
+                fire_at,
+                seq,
+                id,
+                period: Some(period),
+                action,
+            });
+        }
+        true
+    }
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drives a `VirtualClock` by sleeping in wall-clock time until each event's
+/// `fire_at`. Use this to run a generated simulation in real time.
+pub struct RealTimeDriver {
+    clock: VirtualClock,
+    started_at: tokio::time::Instant,
+}
+
+impl RealTimeDriver {
+    pub fn new(clock: VirtualClock) -> Self {
+        Self {
+            clock,
+            started_at: tokio::time::Instant::now(),
+        }
+    }
+
+    /// Run forever, sleeping until each event's simulated `fire_at` and then
+    /// dispatching it.
+    pub async fn run(&self) {
+        loop {
+            match self.clock.next_fire_at() {
+                Some(fire_at) => {
+                    tokio::time::sleep_until(self.started_at + fire_at).await;
+                    self.clock.fire_next();
+                }
+                None => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        }
+    }
+}
+
+/// Drives a `VirtualClock` with no wall-clock sleeping: events fire back to
+/// back as soon as the previous one is processed, bounded by a `run_until`
+/// simulated-time budget. Use this for instant, reproducible simulations.
+pub struct VirtualDriver {
+    clock: VirtualClock,
+}
+
+impl VirtualDriver {
+    pub fn new(clock: VirtualClock) -> Self {
+        Self { clock }
+    }
+
+    /// Fire every event up to and including `sim_time`, jumping the clock
+    /// forward with no sleeping.
+    pub fn run_until(&self, sim_time: Duration) {
+        while let Some(fire_at) = self.clock.next_fire_at() {
+            if fire_at > sim_time {
+                break;
+            }
+            self.clock.fire_next();
+        }
+    }
+
+    /// Fire every remaining event.
+    pub fn run_to_completion(&self) {
+        while self.clock.fire_next() {}
+    }
+}
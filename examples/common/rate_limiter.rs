@@ -0,0 +1,55 @@
+// Generated from ActorSimulation DSL
+// Subsystem: rate limiting
+//
+// Shared by `ractor_pipeline` and `ractor_loadbalanced`; each crate's
+// `src/rate_limiter.rs` is a `#[path]` shim onto this file rather than a
+// copy.
+//
+// Timer-driven actors fired at a fixed rate regardless of whether their
+// downstream could keep up. A `TokenBucket` gates sends instead: each send
+// consumes a token, tokens refill at a configured rate up to a capacity,
+// and a send with no token available is dropped rather than queued.
+
+use std::time::Duration;
+
+/// A token-bucket rate limiter driven by the shared `VirtualClock`'s
+/// simulated time rather than wall-clock `Instant`s, so throttling stays
+/// deterministic across simulation runs.
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Duration,
+}
+
+impl TokenBucket {
+    pub fn new(capacity: usize, refill_per_sec: f64) -> Self {
+        Self {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            refill_per_sec,
+            last_refill: Duration::ZERO,
+        }
+    }
+
+    /// Attempt to consume one token at simulated time `now`. Returns `true`
+    /// if a token was available and consumed, `false` if the send should be
+    /// dropped.
+    pub fn try_consume(&mut self, now: Duration) -> bool {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refill(&mut self, now: Duration) {
+        if now > self.last_refill {
+            let elapsed = (now - self.last_refill).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+            self.last_refill = now;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Generated from ActorSimulation DSL
+// Subsystem: retry
+//
+// Shared by `ractor_pipeline` and `ractor_pubsub`; each crate's
+// `src/retry.rs` is a `#[path]` shim onto this file rather than a copy.
+//
+// Callback traits like `SourceCallbacks::on_data` used to be infallible, so
+// a failing side effect just got lost. A message the DSL marks retryable
+// instead has its callback return a `Result`; on `Err` the actor re-enqueues
+// the message to itself through the shared clock (so retries respect
+// virtual time) until `RetryPolicy::max_retries` is exhausted, delayed by a
+// `Backoff` policy between attempts.
+
+use std::time::Duration;
+
+/// How the delay between retry attempts grows.
+#[derive(Debug, Clone, Copy)]
+pub enum Backoff {
+    /// The same delay every attempt.
+    Fixed(Duration),
+    /// `base * (attempt + 1)`.
+    Linear { base: Duration },
+    /// `base * 2^attempt`, capped at `max`, with a deterministic jitter
+    /// shaved off so repeated simulation runs stay reproducible.
+    ExponentialJitter { base: Duration, max: Duration },
+}
+
+impl Backoff {
+    pub fn delay_for(&self, attempt: usize) -> Duration {
+        match self {
+            Backoff::Fixed(delay) => *delay,
+            Backoff::Linear { base } => *base * (attempt as u32 + 1),
+            Backoff::ExponentialJitter { base, max } => {
+                let exp = base.as_millis().saturating_mul(1u128 << attempt.min(16));
+                let capped = exp.min(max.as_millis());
+                let jitter = capped * deterministic_jitter_permille(attempt) / 1000;
+                Duration::from_millis((capped - jitter) as u64)
+            }
+        }
+    }
+}
+
+/// A stand-in for random jitter that stays deterministic across runs: up to
+/// 20% of the delay, varying with the attempt number.
+fn deterministic_jitter_permille(attempt: usize) -> u128 {
+    ((attempt as u128 * 37 + 11) % 101) * 2
+}
+
+/// Bounds how many times a retryable message is re-enqueued before its
+/// handler gives up and calls `on_failed`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+    pub backoff: Backoff,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Backoff::ExponentialJitter {
+                base: Duration::from_millis(50),
+                max: Duration::from_secs(2),
+            },
+        }
+    }
+}
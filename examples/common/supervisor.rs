@@ -0,0 +1,224 @@
+// Generated from ActorSimulation DSL
+// Subsystem: supervision
+//
+// Shared by `ractor_pipeline` and `ractor_loadbalanced`. Each crate's
+// `src/supervisor.rs` is a `#[path]` shim onto this file rather than a
+// copy, aliasing the generic `Supervisor`/`SupervisorArgs`/
+// `SupervisorState` below to its own crate's names, so there's one
+// implementation to keep in sync.
+//
+// Actors spawned with a bare `Actor::spawn` run unsupervised: a panic
+// silently kills that actor and leaves the rest of the generated graph
+// running in a broken state (disconnected downstream, no balancer
+// forwarding requests, etc). A `Supervisor` spawns a marked child with
+// `spawn_linked` instead, observes `SupervisionEvent::ActorFailed` /
+// `ActorTerminated`, and restarts it per a chosen `RestartStrategy` and
+// `RestartPolicy`, re-running the child's `pre_start` (and so
+// re-establishing its clock-driven timers, or rejoining whatever topology
+// it was wired into) on every restart.
+
+use ractor::{Actor, ActorCell, ActorProcessingErr, ActorRef, SupervisionEvent};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+/// Which children a supervisor restarts when one of them fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartStrategy {
+    /// Only the failed child is restarted.
+    OneForOne,
+    /// Every child under the supervisor is restarted along with the failed
+    /// one.
+    OneForAll,
+}
+
+/// Bounds how aggressively a supervisor restarts its children: at most
+/// `max_restarts` within the trailing `window`, each restart delayed by
+/// `backoff` so a sub-1s restart loop is clamped.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    pub max_restarts: usize,
+    pub window: Duration,
+    pub backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 3,
+            window: Duration::from_secs(5),
+            backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+/// A lifecycle event a user callback can observe.
+#[derive(Debug, Clone)]
+pub enum SupervisionLifecycleEvent {
+    Restarted { child: String },
+    RestartsExhausted { child: String },
+}
+
+/// Implement this to observe supervisor lifecycle events.
+pub trait SupervisorCallbacks: Send + Sync {
+    fn on_event(&self, event: SupervisionLifecycleEvent);
+}
+
+/// DefaultSupervisorCallbacks provides default implementations
+/// CUSTOMIZE THIS to add your own behavior!
+pub struct DefaultSupervisorCallbacks;
+
+impl SupervisorCallbacks for DefaultSupervisorCallbacks {
+    fn on_event(&self, event: SupervisionLifecycleEvent) {
+        println!("Supervisor: {:?}", event);
+    }
+}
+
+/// Re-spawns a supervised child under the supervisor's cell, re-running its
+/// `pre_start`. Returns the new child's `ActorCell` so the supervisor can
+/// keep tracking it.
+pub type RespawnFn = Box<
+    dyn Fn(ActorCell) -> Pin<Box<dyn Future<Output = Result<ActorCell, ActorProcessingErr>> + Send>>
+        + Send
+        + Sync,
+>;
+
+struct Child {
+    name: String,
+    cell: ActorCell,
+    respawn: RespawnFn,
+    restarts: Vec<Instant>,
+}
+
+pub struct SupervisorArgs {
+    pub strategy: RestartStrategy,
+    pub policy: RestartPolicy,
+    pub callbacks: Box<dyn SupervisorCallbacks + Send + Sync>,
+    /// The supervised children, named for diagnostics, with a closure that
+    /// spawns them linked to the supervisor's cell.
+    pub children: Vec<(String, RespawnFn)>,
+}
+
+#[allow(dead_code)]
+pub struct SupervisorState {
+    strategy: RestartStrategy,
+    policy: RestartPolicy,
+    callbacks: Box<dyn SupervisorCallbacks + Send + Sync>,
+    children: Vec<Child>,
+    supervisor_cell: ActorCell,
+}
+
+pub struct Supervisor;
+
+impl Actor for Supervisor {
+    type Msg = ();
+    type State = SupervisorState;
+    type Arguments = SupervisorArgs;
+
+    async fn pre_start(
+        &self,
+        myself: ActorRef<Self::Msg>,
+        args: Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        let mut children = Vec::with_capacity(args.children.len());
+        for (name, respawn) in args.children {
+            let cell = respawn(myself.get_cell()).await?;
+            children.push(Child {
+                name,
+                cell,
+                respawn,
+                restarts: Vec::new(),
+            });
+        }
+
+        Ok(SupervisorState {
+            strategy: args.strategy,
+            policy: args.policy,
+            callbacks: args.callbacks,
+            children,
+            supervisor_cell: myself.get_cell(),
+        })
+    }
+
+    async fn handle_supervisor_evt(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        event: SupervisionEvent,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        let failed_id = match &event {
+            SupervisionEvent::ActorFailed(cell, _) => Some(cell.get_id()),
+            SupervisionEvent::ActorTerminated(cell, _, _) => Some(cell.get_id()),
+            _ => None,
+        };
+        let Some(failed_id) = failed_id else {
+            return Ok(());
+        };
+        // Only the exit of a current child triggers a restart. A sibling
+        // stopped by an earlier OneForAll restart reports its termination
+        // after it has already been replaced; that event is ignored.
+        if !state.children.iter().any(|child| child.cell.get_id() == failed_id) {
+            return Ok(());
+        }
+
+        let restart_all = state.strategy == RestartStrategy::OneForAll;
+        if restart_all {
+            // The failed child is already dying on its own; every other
+            // child is still alive and has to be stopped before it's
+            // respawned, or the old instance keeps running alongside the
+            // new one — a leaked duplicate actor receiving duplicate
+            // deliveries.
+            for child in &state.children {
+                if child.cell.get_id() != failed_id {
+                    child.cell.stop(Some("sibling failed under OneForAll".to_string()));
+                }
+            }
+        }
+        for index in 0..state.children.len() {
+            if !restart_all && state.children[index].cell.get_id() != failed_id {
+                continue;
+            }
+            self.restart_child(index, state).await?;
+        }
+        Ok(())
+    }
+}
+
+impl Supervisor {
+    async fn restart_child(
+        &self,
+        index: usize,
+        state: &mut SupervisorState,
+    ) -> Result<(), ActorProcessingErr> {
+        let now = Instant::now();
+        let window = state.policy.window;
+        let child = &mut state.children[index];
+        child.restarts.retain(|at| now.duration_since(*at) <= window);
+
+        if child.restarts.len() >= state.policy.max_restarts {
+            state
+                .callbacks
+                .on_event(SupervisionLifecycleEvent::RestartsExhausted {
+                    child: child.name.clone(),
+                });
+            return Ok(());
+        }
+
+        child.restarts.push(now);
+        tokio::time::sleep(state.policy.backoff).await;
+
+        let supervisor_cell = state.supervisor_cell.clone();
+        let child = &mut state.children[index];
+        // Link the new child to the supervisor's own cell, not the dying
+        // child's — linking it to the old cell would leave it supervised
+        // by an actor that's already terminating, so a second failure
+        // would never be observed.
+        child.cell = (child.respawn)(supervisor_cell).await?;
+        state
+            .callbacks
+            .on_event(SupervisionLifecycleEvent::Restarted {
+                child: state.children[index].name.clone(),
+            });
+        Ok(())
+    }
+}
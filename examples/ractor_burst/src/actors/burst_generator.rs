@@ -2,9 +2,12 @@
 // Actor: burst_generator
 // DO NOT EDIT - This file is auto-generated
 
+use crate::actors::processor::ProcessorMessage;
+use crate::clock::VirtualClock;
+use crate::events::{ActorEvent, EventSink};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::interval;
 
 /// BurstGeneratorCallbacks defines the callback trait
 /// Implement this trait to customize actor behavior
@@ -12,11 +15,30 @@ pub trait BurstGeneratorCallbacks: Send + Sync {
     fn on_batch(&self);
 }
 
+/// The actors `burst_generator` sends to, resolved from the DSL graph at
+/// startup.
+pub struct BurstGeneratorDownstreams {
+    pub processor: ActorRef<ProcessorMessage>,
+}
+
+/// Arguments passed to `BurstGenerator::spawn`. Carries the shared clock
+/// handle so the burst timer is scheduled in simulated time instead of
+/// spawning a free-running `tokio::time::interval`, the `EventSink` each
+/// handled message is reported to, and the resolved downstream
+/// `ActorRef`s.
+pub struct BurstGeneratorArgs {
+    pub clock: VirtualClock,
+    pub events: Arc<dyn EventSink>,
+    pub downstreams: BurstGeneratorDownstreams,
+}
 
 #[allow(dead_code)]
 pub struct BurstGeneratorState {
     callbacks: Box<dyn BurstGeneratorCallbacks + Send + Sync>,
     send_count: usize,
+    clock: VirtualClock,
+    events: Arc<dyn EventSink>,
+    downstreams: BurstGeneratorDownstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,30 +51,31 @@ pub struct BurstGenerator;
 impl Actor for BurstGenerator {
     type Msg = BurstGeneratorMessage;
     type State = BurstGeneratorState;
-    type Arguments = ();
+    type Arguments = BurstGeneratorArgs;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = BurstGeneratorState {
             callbacks: Box::new(DefaultBurstGeneratorCallbacks),
             send_count: 0,
+            clock: args.clock.clone(),
+            events: args.events.clone(),
+            downstreams: args.downstreams,
         };
 
-        // Spawn burst timer (10 msgs every 1000ms)
+        // Register the burst timer (10 msgs every 1000ms) with the shared
+        // clock instead of spawning a free-running `tokio::time::interval`.
         let actor_ref = myself.clone();
-        tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(1000));
-            loop {
-                interval.tick().await;
+        args.clock
+            .schedule_repeating(Duration::from_millis(1000), move || {
                 for _ in 0..10 {
                     let _ = actor_ref.send_message(Self::Msg::Batch);
                 }
-            }
-        });
+            });
         Ok(state)
     }
 
@@ -67,8 +90,14 @@ impl Actor for BurstGenerator {
             BurstGeneratorMessage::Batch => {
                 state.callbacks.on_batch();
                 state.send_count += 1;
-                // Note: To send to other actors, you would need their ActorRef.
-                // Add target ActorRefs to the state in your custom implementation.
+                state.events.record(ActorEvent::MessageHandled {
+                    actor: "burst_generator".to_string(),
+                    at: state.clock.now(),
+                });
+                let _ = state
+                    .downstreams
+                    .processor
+                    .send_message(ProcessorMessage::Batch { size: 1 });
             }
         }
         Ok(())
@@ -1,23 +1,52 @@
 // Generated from ActorSimulation DSL
 // Actor: processor
 // DO NOT EDIT - This file is auto-generated
+//
+// `processor` declares two named input streams in the DSL: `batches`, fed
+// by `burst_generator`, and `control`, a periodic flush tick. Both are
+// folded into one `ProcessorMessage` variant per stream rather than a
+// `tokio::select!` over separate channels, since ractor already
+// multiplexes every sender into a single mailbox fairly in send order;
+// `handle` dispatches each variant to its own `ProcessorCallbacks` method
+// instead of the single default handler generated for a one-stream actor.
 
+use crate::clock::VirtualClock;
+use crate::events::{ActorEvent, EventSink};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use std::sync::Arc;
+use std::time::Duration;
 
 /// ProcessorCallbacks defines the callback trait
 /// Implement this trait to customize actor behavior
-pub trait ProcessorCallbacks: Send + Sync {}
+pub trait ProcessorCallbacks: Send + Sync {
+    fn on_batch(&self, size: usize);
+    fn on_flush(&self);
+}
 
+/// Arguments passed to `Processor::spawn`. Carries the shared clock handle
+/// used both to timestamp handled messages and to schedule the `control`
+/// stream's periodic flush tick, and the `EventSink` handled messages are
+/// reported to.
+pub struct ProcessorArgs {
+    pub clock: VirtualClock,
+    pub events: Arc<dyn EventSink>,
+}
 
 #[allow(dead_code)]
 pub struct ProcessorState {
     callbacks: Box<dyn ProcessorCallbacks + Send + Sync>,
     send_count: usize,
+    flush_count: usize,
+    clock: VirtualClock,
+    events: Arc<dyn EventSink>,
 }
 
 #[derive(Debug, Clone)]
 pub enum ProcessorMessage {
-    Ping,
+    /// From the `batches` stream: a batch forwarded by `burst_generator`.
+    Batch { size: usize },
+    /// From the `control` stream: a periodic flush tick.
+    Flush,
 }
 
 pub struct Processor;
@@ -25,19 +54,30 @@ pub struct Processor;
 impl Actor for Processor {
     type Msg = ProcessorMessage;
     type State = ProcessorState;
-    type Arguments = ();
+    type Arguments = ProcessorArgs;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = ProcessorState {
             callbacks: Box::new(DefaultProcessorCallbacks),
             send_count: 0,
+            flush_count: 0,
+            clock: args.clock.clone(),
+            events: args.events,
         };
 
+        // Register the `control` stream's flush tick (every 5000ms) with
+        // the shared clock, independently of the `batches` stream fed by
+        // `burst_generator`.
+        let actor_ref = myself.clone();
+        args.clock
+            .schedule_repeating(Duration::from_millis(5000), move || {
+                let _ = actor_ref.send_message(Self::Msg::Flush);
+            });
         Ok(state)
     }
 
@@ -49,8 +89,21 @@ impl Actor for Processor {
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
-            ProcessorMessage::Ping => {
-                // Default message handler
+            ProcessorMessage::Batch { size } => {
+                state.callbacks.on_batch(size);
+                state.send_count += 1;
+                state.events.record(ActorEvent::MessageHandled {
+                    actor: "processor".to_string(),
+                    at: state.clock.now(),
+                });
+            }
+            ProcessorMessage::Flush => {
+                state.callbacks.on_flush();
+                state.flush_count += 1;
+                state.events.record(ActorEvent::MessageHandled {
+                    actor: "processor".to_string(),
+                    at: state.clock.now(),
+                });
             }
         }
         Ok(())
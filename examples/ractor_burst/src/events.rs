@@ -0,0 +1,124 @@
+// Generated from ActorSimulation DSL
+// Subsystem: events
+//
+// Every generated state already tracks a `send_count` that never leaves
+// the actor, so a simulation run yields no measurable output. An
+// `EventSink` is injected into each actor via `Arguments` alongside its
+// `VirtualClock`; actors report a `MessageHandled` event per message,
+// stamped with the simulated time it fired at, instead of updating
+// `send_count` in isolation. `CollectingEventSink` aggregates those into a
+// `SimulationReport` per actor, which the generated `main` prints on
+// shutdown so message counts and throughput can be read off a run instead
+// of staying invisible.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A lifecycle or message-handling event a generated actor reports.
+#[derive(Debug, Clone)]
+pub enum ActorEvent {
+    MessageHandled { actor: String, at: Duration },
+}
+
+/// Where generated actors report their events. `CollectingEventSink` is
+/// the only implementation generated today; the trait exists so a DSL
+/// could swap in, say, a sink that forwards to an external metrics system.
+pub trait EventSink: Send + Sync {
+    fn record(&self, event: ActorEvent);
+}
+
+#[derive(Debug, Clone, Default)]
+struct ActorStats {
+    message_count: usize,
+    first_at: Option<Duration>,
+    last_at: Option<Duration>,
+}
+
+/// Aggregates `ActorEvent`s into per-actor message counts and virtual
+/// timestamps, from which a `SimulationReport` can be produced.
+#[derive(Default)]
+pub struct CollectingEventSink {
+    stats: Mutex<HashMap<String, ActorStats>>,
+}
+
+impl CollectingEventSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot the events collected so far into a `SimulationReport`.
+    pub fn report(&self) -> SimulationReport {
+        let stats = self.stats.lock().unwrap();
+        let mut actors: Vec<ActorReport> = stats
+            .iter()
+            .map(|(actor, stats)| ActorReport {
+                actor: actor.clone(),
+                message_count: stats.message_count,
+                first_at: stats.first_at,
+                last_at: stats.last_at,
+            })
+            .collect();
+        actors.sort_by(|a, b| a.actor.cmp(&b.actor));
+        SimulationReport { actors }
+    }
+}
+
+impl EventSink for CollectingEventSink {
+    fn record(&self, event: ActorEvent) {
+        let ActorEvent::MessageHandled { actor, at } = event;
+        let mut stats = self.stats.lock().unwrap();
+        let entry = stats.entry(actor).or_default();
+        entry.message_count += 1;
+        entry.first_at.get_or_insert(at);
+        entry.last_at = Some(at);
+    }
+}
+
+/// One actor's slice of a `SimulationReport`: how many messages it
+/// handled and the virtual-time span it handled them over.
+#[derive(Debug, Clone)]
+pub struct ActorReport {
+    pub actor: String,
+    pub message_count: usize,
+    pub first_at: Option<Duration>,
+    pub last_at: Option<Duration>,
+}
+
+impl ActorReport {
+    /// Messages per simulated second over `[first_at, last_at]`, or
+    /// `None` if there is no span to divide by: fewer than two messages
+    /// were recorded, or all of them at the same instant.
+    pub fn throughput_per_sec(&self) -> Option<f64> {
+        let (first, last) = (self.first_at?, self.last_at?);
+        if last == first {
+            return None;
+        }
+        let elapsed_secs = (last - first).as_secs_f64();
+        Some(self.message_count as f64 / elapsed_secs)
+    }
+}
+
+/// A snapshot of every actor's message counts and throughput, printed by
+/// the generated `main` on shutdown.
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    pub actors: Vec<ActorReport>,
+}
+
+impl std::fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Simulation report:")?;
+        for actor in &self.actors {
+            match actor.throughput_per_sec() {
+                Some(throughput) => writeln!(
+                    f,
+                    "  {}: {} messages, {:.2} msgs/sec",
+                    actor.actor, actor.message_count, throughput
+                )?,
+                None => writeln!(f, "  {}: {} messages", actor.actor, actor.message_count)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,25 +1,61 @@
 // Generated from ActorSimulation DSL
 // Main entry point for burst_actors
 
-use burst_actors::actors::burst_generator::BurstGenerator;
-use burst_actors::actors::processor::Processor;
+use burst_actors::actors::burst_generator::{
+    BurstGenerator, BurstGeneratorArgs, BurstGeneratorDownstreams,
+};
+use burst_actors::actors::processor::{Processor, ProcessorArgs};
+use burst_actors::clock::{RealTimeDriver, VirtualClock};
+use burst_actors::events::CollectingEventSink;
 use ractor::Actor;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Starting actor system...");
 
-    // Spawn all actors
-    let (_burst_generator_ref, _burst_generator_handle) =
-        BurstGenerator::spawn(None, BurstGenerator, ()).await?;
+    let events = Arc::new(CollectingEventSink::new());
+    let clock = VirtualClock::new();
 
-    let (_processor_ref, _processor_handle) = Processor::spawn(None, Processor, ()).await?;
+    // Spawn in reverse-topological order so `burst_generator` can be
+    // handed the already-spawned `processor` ActorRef it points to in the
+    // DSL graph.
+    let (processor_ref, _processor_handle) = Processor::spawn(
+        None,
+        Processor,
+        ProcessorArgs {
+            clock: clock.clone(),
+            events: events.clone(),
+        },
+    )
+    .await?;
+
+    let (_burst_generator_ref, _burst_generator_handle) = BurstGenerator::spawn(
+        None,
+        BurstGenerator,
+        BurstGeneratorArgs {
+            clock: clock.clone(),
+            events: events.clone(),
+            downstreams: BurstGeneratorDownstreams {
+                processor: processor_ref,
+            },
+        },
+    )
+    .await?;
+
+    // Drive the shared clock in wall-clock time so the timer-based actors
+    // above actually fire.
+    let driver_clock = clock.clone();
+    tokio::spawn(async move {
+        RealTimeDriver::new(driver_clock).run().await;
+    });
 
     println!("Actor system started. Press Ctrl+C to exit.");
 
     // Keep running
     tokio::signal::ctrl_c().await?;
     println!("Shutting down...");
+    print!("{}", events.report());
 
     Ok(())
 }
@@ -11,12 +11,22 @@ async fn test_actor_system() {
 
 #[tokio::test]
 async fn test_processor_spawns() {
-    use burst_actors::actors::processor::Processor;
+    use burst_actors::actors::processor::{Processor, ProcessorArgs};
+    use burst_actors::clock::VirtualClock;
+    use burst_actors::events::CollectingEventSink;
     use ractor::ActorStatus;
+    use std::sync::Arc;
 
-    let (actor_ref, actor_handle) = Processor::spawn(None, Processor, ())
-        .await
-        .expect("Failed to spawn processor");
+    let (actor_ref, actor_handle) = Processor::spawn(
+        None,
+        Processor,
+        ProcessorArgs {
+            clock: VirtualClock::new(),
+            events: Arc::new(CollectingEventSink::new()),
+        },
+    )
+    .await
+    .expect("Failed to spawn processor");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -32,12 +42,41 @@ async fn test_processor_spawns() {
 
 #[tokio::test]
 async fn test_burst_generator_spawns() {
-    use burst_actors::actors::burst_generator::BurstGenerator;
+    use burst_actors::actors::burst_generator::{
+        BurstGenerator, BurstGeneratorArgs, BurstGeneratorDownstreams,
+    };
+    use burst_actors::actors::processor::{Processor, ProcessorArgs};
+    use burst_actors::clock::VirtualClock;
+    use burst_actors::events::CollectingEventSink;
     use ractor::ActorStatus;
+    use std::sync::Arc;
 
-    let (actor_ref, actor_handle) = BurstGenerator::spawn(None, BurstGenerator, ())
-        .await
-        .expect("Failed to spawn burst_generator");
+    let events = Arc::new(CollectingEventSink::new());
+    let clock = VirtualClock::new();
+    let (processor_ref, _processor_handle) = Processor::spawn(
+        None,
+        Processor,
+        ProcessorArgs {
+            clock: clock.clone(),
+            events: events.clone(),
+        },
+    )
+    .await
+    .expect("Failed to spawn processor");
+
+    let (actor_ref, actor_handle) = BurstGenerator::spawn(
+        None,
+        BurstGenerator,
+        BurstGeneratorArgs {
+            clock,
+            events,
+            downstreams: BurstGeneratorDownstreams {
+                processor: processor_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn burst_generator");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -49,3 +88,100 @@ async fn test_burst_generator_spawns() {
     actor_ref.stop(None);
     let _ = actor_handle.await;
 }
+
+#[tokio::test]
+async fn test_processor_handles_batch_and_flush_streams() {
+    use burst_actors::actors::processor::{Processor, ProcessorArgs, ProcessorMessage};
+    use burst_actors::clock::VirtualClock;
+    use burst_actors::events::CollectingEventSink;
+    use ractor::ActorStatus;
+    use std::sync::Arc;
+
+    let events = Arc::new(CollectingEventSink::new());
+    let (actor_ref, actor_handle) = Processor::spawn(
+        None,
+        Processor,
+        ProcessorArgs {
+            clock: VirtualClock::new(),
+            events: events.clone(),
+        },
+    )
+    .await
+    .expect("Failed to spawn processor");
+
+    // `batches` and `control` are independent streams folded into one
+    // mailbox; both variants should be handled without interfering.
+    actor_ref
+        .send_message(ProcessorMessage::Batch { size: 3 })
+        .expect("Failed to send batch");
+    actor_ref
+        .send_message(ProcessorMessage::Flush)
+        .expect("Failed to send flush");
+    tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+    matches!(actor_ref.get_status(), ActorStatus::Running);
+    // Both the batch and the flush are handled messages, so both should be
+    // recorded in the events report.
+    assert_eq!(events.report().actors[0].message_count, 2);
+
+    actor_ref.stop(None);
+    let _ = actor_handle.await;
+}
+
+#[test]
+fn test_collecting_event_sink_aggregates_per_actor() {
+    use burst_actors::events::{ActorEvent, CollectingEventSink, EventSink};
+    use std::time::Duration;
+
+    let sink = CollectingEventSink::new();
+    sink.record(ActorEvent::MessageHandled {
+        actor: "burst_generator".to_string(),
+        at: Duration::ZERO,
+    });
+    sink.record(ActorEvent::MessageHandled {
+        actor: "burst_generator".to_string(),
+        at: Duration::from_secs(1),
+    });
+    sink.record(ActorEvent::MessageHandled {
+        actor: "processor".to_string(),
+        at: Duration::from_millis(500),
+    });
+
+    let report = sink.report();
+    let burst_generator = report
+        .actors
+        .iter()
+        .find(|actor| actor.actor == "burst_generator")
+        .expect("burst_generator report missing");
+    assert_eq!(burst_generator.message_count, 2);
+    assert_eq!(burst_generator.throughput_per_sec(), Some(2.0));
+
+    let processor = report
+        .actors
+        .iter()
+        .find(|actor| actor.actor == "processor")
+        .expect("processor report missing");
+    assert_eq!(processor.message_count, 1);
+    assert_eq!(processor.throughput_per_sec(), None);
+}
+
+#[test]
+fn test_virtual_clock_fast_forwards_deterministically() {
+    use burst_actors::clock::{VirtualClock, VirtualDriver};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let clock = VirtualClock::new();
+    let fired = Arc::new(AtomicUsize::new(0));
+    let counter = fired.clone();
+    clock.schedule_repeating(Duration::from_millis(1000), move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // Fast-forward 3.5 simulated seconds instantly instead of sleeping;
+    // exactly 3 of the 1000ms ticks are due in that span.
+    VirtualDriver::new(clock).run_until(Duration::from_millis(3500));
+
+    assert_eq!(fired.load(Ordering::SeqCst), 3);
+}
@@ -2,21 +2,54 @@
 // Actor: load_balancer
 // DO NOT EDIT - This file is auto-generated
 
+use crate::actors::server1::Server1Message;
+use crate::actors::server2::Server2Message;
+use crate::actors::server3::Server3Message;
+use crate::clock::VirtualClock;
+use crate::rate_limiter::TokenBucket;
+use crate::router::{RouteTarget, Router, RoutingStrategy};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 use std::time::Duration;
-use tokio::time::interval;
 
 /// LoadBalancerCallbacks defines the callback trait
 /// Implement this trait to customize actor behavior
 pub trait LoadBalancerCallbacks: Send + Sync {
     fn on_request(&self);
+
+    /// Called after every `Request` message is resolved (routed or
+    /// dropped), with the running totals so far, so a caller can observe
+    /// the rate limiter's effect without reaching into actor state
+    /// directly.
+    fn on_metrics(&self, _send_count: usize, _dropped_count: usize) {}
+}
+
+/// The actors `load_balancer` sends to, resolved from the DSL graph at
+/// startup and injected here instead of being left for a hand-written
+/// implementation.
+pub struct LoadBalancerDownstreams {
+    pub server1: ActorRef<Server1Message>,
+    pub server2: ActorRef<Server2Message>,
+    pub server3: ActorRef<Server3Message>,
 }
 
+/// Arguments passed to `LoadBalancer::spawn`. Carries the shared clock
+/// handle so the periodic send is scheduled in simulated time instead of
+/// spawning a free-running timer of its own, plus the resolved downstream
+/// `ActorRef`s.
+pub struct LoadBalancerArgs {
+    pub clock: VirtualClock,
+    pub downstreams: LoadBalancerDownstreams,
+    pub strategy: RoutingStrategy,
+    pub callbacks: Box<dyn LoadBalancerCallbacks + Send + Sync>,
+}
 
-#[allow(dead_code)]
 pub struct LoadBalancerState {
     callbacks: Box<dyn LoadBalancerCallbacks + Send + Sync>,
     send_count: usize,
+    dropped_count: usize,
+    clock: VirtualClock,
+    limiter: TokenBucket,
+    router: Router,
 }
 
 #[derive(Debug, Clone)]
@@ -29,28 +62,44 @@ pub struct LoadBalancer;
 impl Actor for LoadBalancer {
     type Msg = LoadBalancerMessage;
     type State = LoadBalancerState;
-    type Arguments = ();
+    type Arguments = LoadBalancerArgs;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
+        let server1 = args.downstreams.server1;
+        let server2 = args.downstreams.server2;
+        let server3 = args.downstreams.server3;
+        let router = Router::new(
+            vec![
+                RouteTarget::new(move || server1.send_message(Server1Message::Ping).is_ok()),
+                RouteTarget::new(move || server2.send_message(Server2Message::Ping).is_ok()),
+                RouteTarget::new(move || server3.send_message(Server3Message::Ping).is_ok()),
+            ],
+            args.strategy,
+        );
+
         let state = LoadBalancerState {
-            callbacks: Box::new(DefaultLoadBalancerCallbacks),
+            callbacks: args.callbacks,
             send_count: 0,
+            dropped_count: 0,
+            clock: args.clock.clone(),
+            // Allows bursts up to 100 messages with steady-state throughput
+            // matching the 100 msgs/sec generation rate.
+            limiter: TokenBucket::new(100, 100.0),
+            router,
         };
 
-        // Spawn rate-based timer (100 msgs/sec)
+        // Register the rate-based send (100 msgs/sec) with the shared clock
+        // instead of spawning a free-running `tokio::time::interval`.
         let actor_ref = myself.clone();
-        tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(10));
-            loop {
-                interval.tick().await;
+        args.clock
+            .schedule_repeating(Duration::from_millis(10), move || {
                 let _ = actor_ref.send_message(Self::Msg::Request);
-            }
-        });
+            });
         Ok(state)
     }
 
@@ -63,10 +112,19 @@ impl Actor for LoadBalancer {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             LoadBalancerMessage::Request => {
-                state.callbacks.on_request();
-                state.send_count += 1;
-                // Note: To send to other actors, you would need their ActorRef.
-                // Add target ActorRefs to the state in your custom implementation.
+                if state.limiter.try_consume(state.clock.now()) {
+                    state.callbacks.on_request();
+                    state.send_count += 1;
+                    if !state.router.dispatch() {
+                        // All servers are down; nothing left to forward to.
+                        state.dropped_count += 1;
+                    }
+                } else {
+                    state.dropped_count += 1;
+                }
+                state
+                    .callbacks
+                    .on_metrics(state.send_count, state.dropped_count);
             }
         }
         Ok(())
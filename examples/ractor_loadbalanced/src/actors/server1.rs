@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: server1
 
+use crate::actors::database::DatabaseMessage;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Server1Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultServer1Callbacks;
 
 impl Server1Callbacks for DefaultServer1Callbacks {}
 
+/// The actors `server1` sends to, resolved from the DSL graph at startup.
+pub struct Server1Downstreams {
+    pub database: ActorRef<DatabaseMessage>,
+}
+
+/// Arguments passed to `Server1::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Server1Args {
+    pub downstreams: Server1Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Server1State {
     callbacks: Box<dyn Server1Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Server1Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Server1;
 impl Actor for Server1 {
     type Msg = Server1Message;
     type State = Server1State;
-    type Arguments = ();
+    type Arguments = Server1Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Server1State {
             callbacks: Box::new(DefaultServer1Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Server1 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Server1Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.database.send_message(DatabaseMessage::Ping);
             }
         }
         Ok(())
@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: server2
 
+use crate::actors::database::DatabaseMessage;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Server2Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultServer2Callbacks;
 
 impl Server2Callbacks for DefaultServer2Callbacks {}
 
+/// The actors `server2` sends to, resolved from the DSL graph at startup.
+pub struct Server2Downstreams {
+    pub database: ActorRef<DatabaseMessage>,
+}
+
+/// Arguments passed to `Server2::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Server2Args {
+    pub downstreams: Server2Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Server2State {
     callbacks: Box<dyn Server2Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Server2Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Server2;
 impl Actor for Server2 {
     type Msg = Server2Message;
     type State = Server2State;
-    type Arguments = ();
+    type Arguments = Server2Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Server2State {
             callbacks: Box::new(DefaultServer2Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Server2 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Server2Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.database.send_message(DatabaseMessage::Ping);
             }
         }
         Ok(())
@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: server3
 
+use crate::actors::database::DatabaseMessage;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Server3Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultServer3Callbacks;
 
 impl Server3Callbacks for DefaultServer3Callbacks {}
 
+/// The actors `server3` sends to, resolved from the DSL graph at startup.
+pub struct Server3Downstreams {
+    pub database: ActorRef<DatabaseMessage>,
+}
+
+/// Arguments passed to `Server3::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Server3Args {
+    pub downstreams: Server3Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Server3State {
     callbacks: Box<dyn Server3Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Server3Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Server3;
 impl Actor for Server3 {
     type Msg = Server3Message;
     type State = Server3State;
-    type Arguments = ();
+    type Arguments = Server3Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Server3State {
             callbacks: Box::new(DefaultServer3Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Server3 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Server3Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.database.send_message(DatabaseMessage::Ping);
             }
         }
         Ok(())
@@ -2,24 +2,131 @@
 // Main entry point for loadbalanced_actors
 
 use loadbalanced_actors::actors::database::Database;
-use loadbalanced_actors::actors::load_balancer::LoadBalancer;
-use loadbalanced_actors::actors::server1::Server1;
-use loadbalanced_actors::actors::server2::Server2;
-use loadbalanced_actors::actors::server3::Server3;
+use loadbalanced_actors::actors::load_balancer::{
+    LoadBalancer, LoadBalancerArgs, LoadBalancerDownstreams,
+};
+use loadbalanced_actors::actors::load_balancer_callbacks::DefaultLoadBalancerCallbacks;
+use loadbalanced_actors::actors::server1::{Server1, Server1Args, Server1Downstreams};
+use loadbalanced_actors::actors::server2::{Server2, Server2Args, Server2Downstreams};
+use loadbalanced_actors::actors::server3::{Server3, Server3Args, Server3Downstreams};
+use loadbalanced_actors::clock::{RealTimeDriver, VirtualClock};
+use loadbalanced_actors::router::RoutingStrategy;
+use loadbalanced_actors::supervisor::{
+    DefaultSupervisorCallbacks, LoadBalancerSupervisor, LoadBalancerSupervisorArgs,
+    RestartPolicy, RestartStrategy,
+};
 use ractor::Actor;
 
+/// Picks the `LoadBalancer`'s `RoutingStrategy` from an optional
+/// `--strategy=<round-robin|random|least-busy>` argument, defaulting to
+/// round-robin when none is given.
+fn routing_strategy_from_args() -> RoutingStrategy {
+    let strategy_arg = std::env::args()
+        .find_map(|arg| arg.strip_prefix("--strategy=").map(str::to_string));
+    match strategy_arg.as_deref() {
+        Some("random") => RoutingStrategy::random(0x2545F4914F6CDD1D),
+        Some("least-busy") => RoutingStrategy::least_busy(),
+        Some("round-robin") | None => RoutingStrategy::round_robin(),
+        Some(other) => {
+            eprintln!("Unknown --strategy={other}, falling back to round-robin");
+            RoutingStrategy::round_robin()
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Starting actor system...");
 
-    // Spawn all actors
-    let (_database_ref, _database_handle) = Database::spawn(None, Database, ()).await?;
-    let (_load_balancer_ref, _load_balancer_handle) =
-        LoadBalancer::spawn(None, LoadBalancer, ()).await?;
+    let clock = VirtualClock::new();
+
+    // Spawn in reverse-topological order so each actor can be handed the
+    // already-spawned `ActorRef`s of the actors it points to in the DSL
+    // graph.
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ()).await?;
+
+    let (server1_ref, _server1_handle) = Server1::spawn(
+        None,
+        Server1,
+        Server1Args {
+            downstreams: Server1Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await?;
+    let (server2_ref, _server2_handle) = Server2::spawn(
+        None,
+        Server2,
+        Server2Args {
+            downstreams: Server2Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await?;
+    let (server3_ref, _server3_handle) = Server3::spawn(
+        None,
+        Server3,
+        Server3Args {
+            downstreams: Server3Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await?;
+
+    // `load_balancer` is the actor the DSL marks as supervised: spawn it
+    // under a `LoadBalancerSupervisor` instead of spawning it directly, so
+    // a panic in its routing logic restarts it (re-wiring it to
+    // `server1/2/3` and re-registering its clock-driven send) rather than
+    // leaving the servers with no balancer forwarding requests to them.
+    let balancer_clock = clock.clone();
+    let (_supervisor_ref, _supervisor_handle) = LoadBalancerSupervisor::spawn(
+        None,
+        LoadBalancerSupervisor,
+        LoadBalancerSupervisorArgs {
+            strategy: RestartStrategy::OneForOne,
+            policy: RestartPolicy::default(),
+            callbacks: Box::new(DefaultSupervisorCallbacks),
+            children: vec![(
+                "load_balancer".to_string(),
+                Box::new(move |supervisor_cell| {
+                    let clock = balancer_clock.clone();
+                    let server1 = server1_ref.clone();
+                    let server2 = server2_ref.clone();
+                    let server3 = server3_ref.clone();
+                    Box::pin(async move {
+                        let (load_balancer_ref, _load_balancer_handle) = LoadBalancer::spawn_linked(
+                            None,
+                            LoadBalancer,
+                            LoadBalancerArgs {
+                                clock,
+                                downstreams: LoadBalancerDownstreams {
+                                    server1,
+                                    server2,
+                                    server3,
+                                },
+                                strategy: routing_strategy_from_args(),
+                                callbacks: Box::new(DefaultLoadBalancerCallbacks),
+                            },
+                            supervisor_cell,
+                        )
+                        .await?;
+                        Ok(load_balancer_ref.get_cell())
+                    })
+                }),
+            )],
+        },
+    )
+    .await?;
 
-    let (_server1_ref, _server1_handle) = Server1::spawn(None, Server1, ()).await?;
-    let (_server2_ref, _server2_handle) = Server2::spawn(None, Server2, ()).await?;
-    let (_server3_ref, _server3_handle) = Server3::spawn(None, Server3, ()).await?;
+    // Drive the shared clock in wall-clock time so the timer-based actors
+    // above actually fire.
+    let driver_clock = clock.clone();
+    tokio::spawn(async move {
+        RealTimeDriver::new(driver_clock).run().await;
+    });
 
     println!("Actor system started. Press Ctrl+C to exit.");
 
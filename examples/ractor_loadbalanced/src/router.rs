@@ -0,0 +1,123 @@
+// Generated from ActorSimulation DSL
+// Subsystem: router
+//
+// `LoadBalancer` used to print "Sending request message" with no way to
+// actually distribute work across `server1/2/3`. A `Router` holds the
+// downstream servers and a `RoutingStrategy` picked by the DSL, and
+// `dispatch` forwards one request to the server the strategy selects,
+// skipping to the next available server when a target is down instead of
+// dropping the request outright. Servers' `Msg` types differ per DSL
+// actor, so a target's send is type-erased behind a `RouteTarget` closure
+// instead of storing a concrete `ActorRef<T>`.
+
+/// A type-erased dispatch target, so a `Router` can hold a homogeneous
+/// `Vec` of servers whose DSL-generated `Msg` types differ. `send`
+/// forwards one message and returns whether delivery succeeded (`false`
+/// once the target has terminated, so the router can skip it).
+pub struct RouteTarget {
+    send: Box<dyn Fn() -> bool + Send>,
+}
+
+impl RouteTarget {
+    pub fn new<F>(send: F) -> Self
+    where
+        F: Fn() -> bool + Send + 'static,
+    {
+        Self {
+            send: Box::new(send),
+        }
+    }
+}
+
+/// How a balancer picks which server to forward a request to.
+pub enum RoutingStrategy {
+    /// Cycle through servers in order.
+    RoundRobin { next: usize },
+    /// Pick pseudo-randomly; `seed` advances deterministically (an LCG)
+    /// rather than drawing on real randomness, so simulation runs stay
+    /// reproducible.
+    Random { seed: u64 },
+    /// Pick whichever server currently has the fewest outstanding
+    /// dispatches. This example's servers don't reply, so "outstanding"
+    /// degrades to a running dispatch count per server rather than a
+    /// true in-flight count.
+    LeastBusy,
+}
+
+impl RoutingStrategy {
+    pub fn round_robin() -> Self {
+        RoutingStrategy::RoundRobin { next: 0 }
+    }
+
+    /// `seed` is the LCG's starting state; any nonzero value works, and the
+    /// same seed reproduces the same sequence of picks across runs.
+    pub fn random(seed: u64) -> Self {
+        RoutingStrategy::Random { seed }
+    }
+
+    pub fn least_busy() -> Self {
+        RoutingStrategy::LeastBusy
+    }
+}
+
+/// Dispatches one request to a server per `strategy`, skipping over
+/// targets whose `send` fails (the server is down) until one accepts it
+/// or all have been tried.
+pub struct Router {
+    targets: Vec<RouteTarget>,
+    outstanding: Vec<usize>,
+    strategy: RoutingStrategy,
+}
+
+impl Router {
+    pub fn new(targets: Vec<RouteTarget>, strategy: RoutingStrategy) -> Self {
+        let outstanding = vec![0; targets.len()];
+        Self {
+            targets,
+            outstanding,
+            strategy,
+        }
+    }
+
+    /// Picks a target per `strategy`, forwards to it, and returns
+    /// whether any target accepted the request.
+    pub fn dispatch(&mut self) -> bool {
+        let len = self.targets.len();
+        if len == 0 {
+            return false;
+        }
+        let start = self.pick_start(len);
+        for offset in 0..len {
+            let index = (start + offset) % len;
+            if (self.targets[index].send)() {
+                self.outstanding[index] += 1;
+                self.advance(index, len);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn pick_start(&mut self, len: usize) -> usize {
+        match &mut self.strategy {
+            RoutingStrategy::RoundRobin { next } => *next % len,
+            RoutingStrategy::Random { seed } => {
+                *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                ((*seed >> 33) as usize) % len
+            }
+            RoutingStrategy::LeastBusy => self
+                .outstanding
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, count)| **count)
+                .map(|(index, _)| index)
+                .unwrap_or(0),
+        }
+    }
+
+    fn advance(&mut self, dispatched_index: usize, len: usize) {
+        if let RoutingStrategy::RoundRobin { next } = &mut self.strategy {
+            *next = (dispatched_index + 1) % len;
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// Shared with `ractor_pipeline` — see `examples/common/supervisor.rs` for
+// the implementation. This file just re-exports it, aliasing the generic
+// `Supervisor` types to this crate's names so `crate::supervisor::...`
+// keeps working unchanged.
+#[path = "../../common/supervisor.rs"]
+mod shared;
+pub use shared::{
+    DefaultSupervisorCallbacks, RespawnFn, RestartPolicy, RestartStrategy,
+    SupervisionLifecycleEvent, SupervisorCallbacks,
+};
+pub use shared::Supervisor as LoadBalancerSupervisor;
+pub use shared::SupervisorArgs as LoadBalancerSupervisorArgs;
+pub use shared::SupervisorState as LoadBalancerSupervisorState;
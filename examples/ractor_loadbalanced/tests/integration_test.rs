@@ -11,12 +11,71 @@ async fn test_actor_system() {
 
 #[tokio::test]
 async fn test_load_balancer_spawns() {
-    use loadbalanced_actors::actors::load_balancer::LoadBalancer;
+    use loadbalanced_actors::actors::database::Database;
+    use loadbalanced_actors::actors::load_balancer::{
+        LoadBalancer, LoadBalancerArgs, LoadBalancerDownstreams,
+    };
+    use loadbalanced_actors::actors::load_balancer_callbacks::DefaultLoadBalancerCallbacks;
+    use loadbalanced_actors::actors::server1::{Server1, Server1Args, Server1Downstreams};
+    use loadbalanced_actors::actors::server2::{Server2, Server2Args, Server2Downstreams};
+    use loadbalanced_actors::actors::server3::{Server3, Server3Args, Server3Downstreams};
+    use loadbalanced_actors::clock::VirtualClock;
+    use loadbalanced_actors::router::RoutingStrategy;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = LoadBalancer::spawn(None, LoadBalancer, ())
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ())
         .await
-        .expect("Failed to spawn load_balancer");
+        .expect("Failed to spawn database");
+    let (server1_ref, _server1_handle) = Server1::spawn(
+        None,
+        Server1,
+        Server1Args {
+            downstreams: Server1Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server1");
+    let (server2_ref, _server2_handle) = Server2::spawn(
+        None,
+        Server2,
+        Server2Args {
+            downstreams: Server2Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server2");
+    let (server3_ref, _server3_handle) = Server3::spawn(
+        None,
+        Server3,
+        Server3Args {
+            downstreams: Server3Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server3");
+
+    let (actor_ref, actor_handle) = LoadBalancer::spawn(
+        None,
+        LoadBalancer,
+        LoadBalancerArgs {
+            clock: VirtualClock::new(),
+            downstreams: LoadBalancerDownstreams {
+                server1: server1_ref,
+                server2: server2_ref,
+                server3: server3_ref,
+            },
+            strategy: RoutingStrategy::round_robin(),
+            callbacks: Box::new(DefaultLoadBalancerCallbacks),
+        },
+    )
+    .await
+    .expect("Failed to spawn load_balancer");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -29,15 +88,477 @@ async fn test_load_balancer_spawns() {
     let _ = actor_handle.await;
 }
 
+#[tokio::test]
+async fn test_load_balancer_reports_send_and_dropped_counts_via_metrics() {
+    use loadbalanced_actors::actors::database::Database;
+    use loadbalanced_actors::actors::load_balancer::{
+        LoadBalancer, LoadBalancerArgs, LoadBalancerCallbacks, LoadBalancerDownstreams,
+    };
+    use loadbalanced_actors::actors::server1::{Server1, Server1Args, Server1Downstreams};
+    use loadbalanced_actors::actors::server2::{Server2, Server2Args, Server2Downstreams};
+    use loadbalanced_actors::actors::server3::{Server3, Server3Args, Server3Downstreams};
+    use loadbalanced_actors::clock::{VirtualClock, VirtualDriver};
+    use loadbalanced_actors::router::RoutingStrategy;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct ObservingCallbacks {
+        last_send_count: Arc<AtomicUsize>,
+        last_dropped_count: Arc<AtomicUsize>,
+    }
+
+    impl LoadBalancerCallbacks for ObservingCallbacks {
+        fn on_request(&self) {}
+
+        fn on_metrics(&self, send_count: usize, dropped_count: usize) {
+            self.last_send_count.store(send_count, Ordering::SeqCst);
+            self.last_dropped_count
+                .store(dropped_count, Ordering::SeqCst);
+        }
+    }
+
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ())
+        .await
+        .expect("Failed to spawn database");
+    let (server1_ref, _server1_handle) = Server1::spawn(
+        None,
+        Server1,
+        Server1Args {
+            downstreams: Server1Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server1");
+    let (server2_ref, _server2_handle) = Server2::spawn(
+        None,
+        Server2,
+        Server2Args {
+            downstreams: Server2Downstreams {
+                database: database_ref.clone(),
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server2");
+    let (server3_ref, _server3_handle) = Server3::spawn(
+        None,
+        Server3,
+        Server3Args {
+            downstreams: Server3Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server3");
+
+    let last_send_count = Arc::new(AtomicUsize::new(0));
+    let last_dropped_count = Arc::new(AtomicUsize::new(0));
+    let clock = VirtualClock::new();
+
+    let (actor_ref, actor_handle) = LoadBalancer::spawn(
+        None,
+        LoadBalancer,
+        LoadBalancerArgs {
+            clock: clock.clone(),
+            downstreams: LoadBalancerDownstreams {
+                server1: server1_ref,
+                server2: server2_ref,
+                server3: server3_ref,
+            },
+            strategy: RoutingStrategy::round_robin(),
+            callbacks: Box::new(ObservingCallbacks {
+                last_send_count: last_send_count.clone(),
+                last_dropped_count: last_dropped_count.clone(),
+            }),
+        },
+    )
+    .await
+    .expect("Failed to spawn load_balancer");
+
+    // One tick within the 100-token bucket, so it should be routed rather
+    // than dropped, and `on_metrics` should reflect that immediately.
+    let driver = VirtualDriver::new(clock.clone());
+    driver.run_until(Duration::from_millis(10));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(last_send_count.load(Ordering::SeqCst), 1);
+    assert_eq!(last_dropped_count.load(Ordering::SeqCst), 0);
+
+    // Clean up
+    actor_ref.stop(None);
+    let _ = actor_handle.await;
+}
+
+#[tokio::test]
+async fn test_supervisor_restarts_child_on_repeated_panics() {
+    use loadbalanced_actors::supervisor::{
+        DefaultSupervisorCallbacks, LoadBalancerSupervisor, LoadBalancerSupervisorArgs,
+        RestartPolicy, RestartStrategy,
+    };
+    use ractor::{Actor, ActorProcessingErr, ActorRef};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    struct Flaky;
+
+    #[derive(Debug, Clone)]
+    enum FlakyMessage {
+        Panic,
+    }
+
+    impl Actor for Flaky {
+        type Msg = FlakyMessage;
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            match message {
+                FlakyMessage::Panic => Err("boom".into()),
+            }
+        }
+    }
+
+    let spawn_count = Arc::new(AtomicUsize::new(0));
+    let latest_ref: Arc<Mutex<Option<ActorRef<FlakyMessage>>>> = Arc::new(Mutex::new(None));
+    let spawn_count_for_respawn = spawn_count.clone();
+    let latest_ref_for_respawn = latest_ref.clone();
+
+    let (_supervisor_ref, _supervisor_handle) = LoadBalancerSupervisor::spawn(
+        None,
+        LoadBalancerSupervisor,
+        LoadBalancerSupervisorArgs {
+            strategy: RestartStrategy::OneForOne,
+            policy: RestartPolicy {
+                max_restarts: 5,
+                window: std::time::Duration::from_secs(5),
+                backoff: std::time::Duration::from_millis(1),
+            },
+            callbacks: Box::new(DefaultSupervisorCallbacks),
+            children: vec![(
+                "flaky".to_string(),
+                Box::new(move |supervisor_cell| {
+                    let spawn_count = spawn_count_for_respawn.clone();
+                    let latest_ref = latest_ref_for_respawn.clone();
+                    Box::pin(async move {
+                        spawn_count.fetch_add(1, Ordering::SeqCst);
+                        let (flaky_ref, _flaky_handle) =
+                            Flaky::spawn_linked(None, Flaky, (), supervisor_cell).await?;
+                        *latest_ref.lock().unwrap() = Some(flaky_ref.clone());
+                        Ok(flaky_ref.get_cell())
+                    })
+                }),
+            )],
+        },
+    )
+    .await
+    .expect("Failed to spawn supervisor");
+
+    assert_eq!(spawn_count.load(Ordering::SeqCst), 1);
+
+    // First panic: the supervisor should observe the failure and respawn.
+    let first_ref = latest_ref.lock().unwrap().clone().unwrap();
+    first_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        spawn_count.load(Ordering::SeqCst),
+        2,
+        "first panic should trigger a restart"
+    );
+
+    // Second panic, on the *new* child: if the restarted child had been
+    // linked to the dead original cell instead of the supervisor's own
+    // cell, this failure would never be observed and spawn_count would
+    // stay at 2.
+    let second_ref = latest_ref.lock().unwrap().clone().unwrap();
+    second_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        spawn_count.load(Ordering::SeqCst),
+        3,
+        "second panic should also trigger a restart"
+    );
+}
+
+#[tokio::test]
+async fn test_one_for_all_restarts_and_stops_healthy_siblings() {
+    use loadbalanced_actors::supervisor::{
+        DefaultSupervisorCallbacks, LoadBalancerSupervisor, LoadBalancerSupervisorArgs,
+        RestartPolicy, RestartStrategy,
+    };
+    use ractor::{Actor, ActorProcessingErr, ActorRef, ActorStatus};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    struct Flaky;
+
+    #[derive(Debug, Clone)]
+    enum FlakyMessage {
+        Panic,
+    }
+
+    impl Actor for Flaky {
+        type Msg = FlakyMessage;
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            match message {
+                FlakyMessage::Panic => Err("boom".into()),
+            }
+        }
+    }
+
+    struct Healthy;
+
+    impl Actor for Healthy {
+        type Msg = ();
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            Ok(())
+        }
+    }
+
+    let flaky_spawn_count = Arc::new(AtomicUsize::new(0));
+    let flaky_latest_ref: Arc<Mutex<Option<ActorRef<FlakyMessage>>>> = Arc::new(Mutex::new(None));
+    let healthy_spawn_count = Arc::new(AtomicUsize::new(0));
+    let healthy_latest_ref: Arc<Mutex<Option<ActorRef<()>>>> = Arc::new(Mutex::new(None));
+
+    let flaky_spawn_count_for_respawn = flaky_spawn_count.clone();
+    let flaky_latest_ref_for_respawn = flaky_latest_ref.clone();
+    let healthy_spawn_count_for_respawn = healthy_spawn_count.clone();
+    let healthy_latest_ref_for_respawn = healthy_latest_ref.clone();
+
+    let (_supervisor_ref, _supervisor_handle) = LoadBalancerSupervisor::spawn(
+        None,
+        LoadBalancerSupervisor,
+        LoadBalancerSupervisorArgs {
+            strategy: RestartStrategy::OneForAll,
+            policy: RestartPolicy {
+                max_restarts: 5,
+                window: std::time::Duration::from_secs(5),
+                backoff: std::time::Duration::from_millis(1),
+            },
+            callbacks: Box::new(DefaultSupervisorCallbacks),
+            children: vec![
+                (
+                    "flaky".to_string(),
+                    Box::new(move |supervisor_cell| {
+                        let spawn_count = flaky_spawn_count_for_respawn.clone();
+                        let latest_ref = flaky_latest_ref_for_respawn.clone();
+                        Box::pin(async move {
+                            spawn_count.fetch_add(1, Ordering::SeqCst);
+                            let (flaky_ref, _flaky_handle) =
+                                Flaky::spawn_linked(None, Flaky, (), supervisor_cell).await?;
+                            *latest_ref.lock().unwrap() = Some(flaky_ref.clone());
+                            Ok(flaky_ref.get_cell())
+                        })
+                    }),
+                ),
+                (
+                    "healthy".to_string(),
+                    Box::new(move |supervisor_cell| {
+                        let spawn_count = healthy_spawn_count_for_respawn.clone();
+                        let latest_ref = healthy_latest_ref_for_respawn.clone();
+                        Box::pin(async move {
+                            spawn_count.fetch_add(1, Ordering::SeqCst);
+                            let (healthy_ref, _healthy_handle) =
+                                Healthy::spawn_linked(None, Healthy, (), supervisor_cell).await?;
+                            *latest_ref.lock().unwrap() = Some(healthy_ref.clone());
+                            Ok(healthy_ref.get_cell())
+                        })
+                    }),
+                ),
+            ],
+        },
+    )
+    .await
+    .expect("Failed to spawn supervisor");
+
+    assert_eq!(flaky_spawn_count.load(Ordering::SeqCst), 1);
+    assert_eq!(healthy_spawn_count.load(Ordering::SeqCst), 1);
+
+    let original_healthy_ref = healthy_latest_ref.lock().unwrap().clone().unwrap();
+    let flaky_ref = flaky_latest_ref.lock().unwrap().clone().unwrap();
+    flaky_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // OneForAll restarts every child, not just the one that failed.
+    assert_eq!(
+        flaky_spawn_count.load(Ordering::SeqCst),
+        2,
+        "the failed child should be restarted"
+    );
+    assert_eq!(
+        healthy_spawn_count.load(Ordering::SeqCst),
+        2,
+        "the healthy sibling should also be restarted under OneForAll"
+    );
+
+    // The original healthy instance must actually be stopped, not left
+    // running alongside its replacement as a leaked duplicate.
+    assert_eq!(original_healthy_ref.get_status(), ActorStatus::Stopped);
+}
+
+#[test]
+fn test_router_round_robin_skips_down_targets() {
+    use loadbalanced_actors::router::{RouteTarget, Router, RoutingStrategy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let hits = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let targets = (0..3)
+        .map(|index| {
+            let hits = hits.clone();
+            RouteTarget::new(move || {
+                // server1 (index 0) is down for this test.
+                if index == 0 {
+                    return false;
+                }
+                hits[index].fetch_add(1, Ordering::SeqCst);
+                true
+            })
+        })
+        .collect();
+    let mut router = Router::new(targets, RoutingStrategy::round_robin());
+
+    for _ in 0..4 {
+        assert!(router.dispatch());
+    }
+
+    // server1 never accepted a request; server2/server3 split the 4
+    // dispatches between them instead of the whole batch silently
+    // failing.
+    assert_eq!(hits[0].load(Ordering::SeqCst), 0);
+    assert_eq!(hits[1].load(Ordering::SeqCst) + hits[2].load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_router_random_skips_down_targets() {
+    use loadbalanced_actors::router::{RouteTarget, Router, RoutingStrategy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let hits = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let targets = (0..3)
+        .map(|index| {
+            let hits = hits.clone();
+            RouteTarget::new(move || {
+                // server1 (index 0) is down for this test.
+                if index == 0 {
+                    return false;
+                }
+                hits[index].fetch_add(1, Ordering::SeqCst);
+                true
+            })
+        })
+        .collect();
+    let mut router = Router::new(targets, RoutingStrategy::random(42));
+
+    for _ in 0..20 {
+        assert!(router.dispatch());
+    }
+
+    // server1 never accepted a request regardless of which server the LCG
+    // picked first; server2/server3 split the 20 dispatches between them.
+    assert_eq!(hits[0].load(Ordering::SeqCst), 0);
+    assert_eq!(hits[1].load(Ordering::SeqCst) + hits[2].load(Ordering::SeqCst), 20);
+}
+
+#[test]
+fn test_router_least_busy_balances_across_targets() {
+    use loadbalanced_actors::router::{RouteTarget, Router, RoutingStrategy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let hits = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let targets = (0..3)
+        .map(|index| {
+            let hits = hits.clone();
+            RouteTarget::new(move || {
+                hits[index].fetch_add(1, Ordering::SeqCst);
+                true
+            })
+        })
+        .collect();
+    let mut router = Router::new(targets, RoutingStrategy::least_busy());
+
+    for _ in 0..9 {
+        assert!(router.dispatch());
+    }
+
+    // With every target always up, least-busy keeps picking whichever has
+    // the fewest dispatches so far, which spreads the 9 dispatches evenly.
+    assert_eq!(hits[0].load(Ordering::SeqCst), 3);
+    assert_eq!(hits[1].load(Ordering::SeqCst), 3);
+    assert_eq!(hits[2].load(Ordering::SeqCst), 3);
+}
+
 
 #[tokio::test]
 async fn test_server1_spawns() {
-    use loadbalanced_actors::actors::server1::Server1;
+    use loadbalanced_actors::actors::database::Database;
+    use loadbalanced_actors::actors::server1::{Server1, Server1Args, Server1Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Server1::spawn(None, Server1, ())
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ())
         .await
-        .expect("Failed to spawn server1");
+        .expect("Failed to spawn database");
+    let (actor_ref, actor_handle) = Server1::spawn(
+        None,
+        Server1,
+        Server1Args {
+            downstreams: Server1Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server1");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -53,12 +574,24 @@ async fn test_server1_spawns() {
 
 #[tokio::test]
 async fn test_server2_spawns() {
-    use loadbalanced_actors::actors::server2::Server2;
+    use loadbalanced_actors::actors::database::Database;
+    use loadbalanced_actors::actors::server2::{Server2, Server2Args, Server2Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Server2::spawn(None, Server2, ())
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ())
         .await
-        .expect("Failed to spawn server2");
+        .expect("Failed to spawn database");
+    let (actor_ref, actor_handle) = Server2::spawn(
+        None,
+        Server2,
+        Server2Args {
+            downstreams: Server2Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server2");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -74,12 +607,24 @@ async fn test_server2_spawns() {
 
 #[tokio::test]
 async fn test_server3_spawns() {
-    use loadbalanced_actors::actors::server3::Server3;
+    use loadbalanced_actors::actors::database::Database;
+    use loadbalanced_actors::actors::server3::{Server3, Server3Args, Server3Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Server3::spawn(None, Server3, ())
+    let (database_ref, _database_handle) = Database::spawn(None, Database, ())
         .await
-        .expect("Failed to spawn server3");
+        .expect("Failed to spawn database");
+    let (actor_ref, actor_handle) = Server3::spawn(
+        None,
+        Server3,
+        Server3Args {
+            downstreams: Server3Downstreams {
+                database: database_ref,
+            },
+        },
+    )
+    .await
+    .expect("Failed to spawn server3");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
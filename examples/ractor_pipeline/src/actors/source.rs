@@ -1,14 +1,31 @@
 // Generated from ActorSimulation DSL
 // Actor: source
 
+use crate::actors::stage1::Stage1Message;
+use crate::clock::VirtualClock;
+use crate::rate_limiter::TokenBucket;
+use crate::retry::RetryPolicy;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::interval;
 
 /// SourceCallbacks defines the callback trait
 /// Implement this trait to customize actor behavior
 pub trait SourceCallbacks: Send + Sync {
-    fn on_data(&self);
+    /// `Data` is retryable: an `Err` causes the message to be re-enqueued
+    /// through the clock per `RetryPolicy`, instead of being dropped.
+    fn on_data(&self) -> Result<(), String>;
+
+    /// Called once `RetryPolicy::max_retries` is exhausted for a message.
+    fn on_failed(&self) {
+        println!("Source: giving up on data message after exhausting retries");
+    }
+
+    /// Called after every `Data` message is resolved (sent or dropped),
+    /// with the running totals so far, so a caller can observe the rate
+    /// limiter's effect without reaching into actor state directly.
+    fn on_metrics(&self, _send_count: usize, _dropped_count: usize) {}
 }
 
 /// DefaultSourceCallbacks provides default implementations
@@ -16,16 +33,43 @@ pub trait SourceCallbacks: Send + Sync {
 pub struct DefaultSourceCallbacks;
 
 impl SourceCallbacks for DefaultSourceCallbacks {
-    fn on_data(&self) {
+    fn on_data(&self) -> Result<(), String> {
         // TODO: Implement custom behavior for data
         println!("Source: Sending data message");
+        Ok(())
     }
 }
 
-#[allow(dead_code)]
+/// The actors `source` sends to, resolved from the DSL graph at startup and
+/// injected here instead of being left for a hand-written implementation.
+pub struct SourceDownstreams {
+    pub stage1: ActorRef<Stage1Message>,
+}
+
+/// Arguments passed to `Source::spawn`. Carries the shared clock handle so
+/// the periodic send is scheduled in simulated time instead of spawning a
+/// free-running timer of its own, plus the resolved downstream `ActorRef`s.
+pub struct SourceArgs {
+    pub clock: VirtualClock,
+    pub downstreams: SourceDownstreams,
+    pub retry_policy: RetryPolicy,
+    pub callbacks: Box<dyn SourceCallbacks + Send + Sync>,
+}
+
 pub struct SourceState {
     callbacks: Box<dyn SourceCallbacks + Send + Sync>,
     send_count: usize,
+    dropped_count: usize,
+    downstreams: SourceDownstreams,
+    clock: VirtualClock,
+    limiter: TokenBucket,
+    attempts: usize,
+    retry_policy: RetryPolicy,
+    // Set while a retried `Data` message is outstanding, so the periodic
+    // 20ms schedule below skips sending a fresh one in the meantime —
+    // otherwise an unrelated tick would race the pending retry and bump or
+    // reset `attempts` for a message that isn't the one backing off.
+    retry_pending: Arc<AtomicBool>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,45 +82,80 @@ pub struct Source;
 impl Actor for Source {
     type Msg = SourceMessage;
     type State = SourceState;
-    type Arguments = ();
+    type Arguments = SourceArgs;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
+        let retry_pending = Arc::new(AtomicBool::new(false));
         let state = SourceState {
-            callbacks: Box::new(DefaultSourceCallbacks),
+            callbacks: args.callbacks,
             send_count: 0,
+            dropped_count: 0,
+            downstreams: args.downstreams,
+            clock: args.clock.clone(),
+            // Allows bursts up to 50 messages with steady-state throughput
+            // matching the 50 msgs/sec generation rate.
+            limiter: TokenBucket::new(50, 50.0),
+            attempts: 0,
+            retry_policy: args.retry_policy,
+            retry_pending: retry_pending.clone(),
         };
 
-        // Spawn rate-based timer (50 msgs/sec)
+        // Register the rate-based send (50 msgs/sec) with the shared clock
+        // instead of spawning a free-running `tokio::time::interval`. Skips
+        // a tick while a retry is outstanding, so it can't race a
+        // still-backing-off message.
         let actor_ref = myself.clone();
-        tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(20));
-            loop {
-                interval.tick().await;
-                let _ = actor_ref.send_message(Self::Msg::Data);
-            }
-        });
+        args.clock
+            .schedule_repeating(Duration::from_millis(20), move || {
+                if !retry_pending.load(Ordering::SeqCst) {
+                    let _ = actor_ref.send_message(Self::Msg::Data);
+                }
+            });
         Ok(state)
     }
 
     #[allow(unused_variables)]
     async fn handle(
         &self,
-        _myself: ActorRef<Self::Msg>,
+        myself: ActorRef<Self::Msg>,
         message: Self::Msg,
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
-            SourceMessage::Data => {
-                state.callbacks.on_data();
-                state.send_count += 1;
-                // Note: To send to other actors, you would need their ActorRef.
-                // Add target ActorRefs to the state in your custom implementation.
-            }
+            SourceMessage::Data => match state.callbacks.on_data() {
+                Ok(()) => {
+                    state.attempts = 0;
+                    state.retry_pending.store(false, Ordering::SeqCst);
+                    if state.limiter.try_consume(state.clock.now()) {
+                        state.send_count += 1;
+                        let _ = state.downstreams.stage1.send_message(Stage1Message::Ping);
+                    } else {
+                        state.dropped_count += 1;
+                    }
+                    state
+                        .callbacks
+                        .on_metrics(state.send_count, state.dropped_count);
+                }
+                Err(_) if state.attempts < state.retry_policy.max_retries => {
+                    state.retry_pending.store(true, Ordering::SeqCst);
+                    let delay = state.retry_policy.backoff.delay_for(state.attempts);
+                    state.attempts += 1;
+                    let actor_ref = myself.clone();
+                    state.clock.schedule_once(delay, move || {
+                        let _ = actor_ref.send_message(Self::Msg::Data);
+                    });
+                }
+                Err(_) => {
+                    state.attempts = 0;
+                    state.retry_pending.store(false, Ordering::SeqCst);
+                    state.callbacks.on_failed();
+                }
+            },
         }
         Ok(())
     }
@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: stage1
 
+use crate::actors::stage2::Stage2Message;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Stage1Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultStage1Callbacks;
 
 impl Stage1Callbacks for DefaultStage1Callbacks {}
 
+/// The actors `stage1` sends to, resolved from the DSL graph at startup.
+pub struct Stage1Downstreams {
+    pub stage2: ActorRef<Stage2Message>,
+}
+
+/// Arguments passed to `Stage1::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Stage1Args {
+    pub downstreams: Stage1Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Stage1State {
     callbacks: Box<dyn Stage1Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Stage1Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Stage1;
 impl Actor for Stage1 {
     type Msg = Stage1Message;
     type State = Stage1State;
-    type Arguments = ();
+    type Arguments = Stage1Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Stage1State {
             callbacks: Box::new(DefaultStage1Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Stage1 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Stage1Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.stage2.send_message(Stage2Message::Ping);
             }
         }
         Ok(())
@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: stage2
 
+use crate::actors::stage3::Stage3Message;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Stage2Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultStage2Callbacks;
 
 impl Stage2Callbacks for DefaultStage2Callbacks {}
 
+/// The actors `stage2` sends to, resolved from the DSL graph at startup.
+pub struct Stage2Downstreams {
+    pub stage3: ActorRef<Stage3Message>,
+}
+
+/// Arguments passed to `Stage2::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Stage2Args {
+    pub downstreams: Stage2Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Stage2State {
     callbacks: Box<dyn Stage2Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Stage2Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Stage2;
 impl Actor for Stage2 {
     type Msg = Stage2Message;
     type State = Stage2State;
-    type Arguments = ();
+    type Arguments = Stage2Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Stage2State {
             callbacks: Box::new(DefaultStage2Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Stage2 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Stage2Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.stage3.send_message(Stage3Message::Ping);
             }
         }
         Ok(())
@@ -1,6 +1,7 @@
 // Generated from ActorSimulation DSL
 // Actor: stage3
 
+use crate::actors::sink::SinkMessage;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Stage3Callbacks defines the callback trait
@@ -13,10 +14,22 @@ pub struct DefaultStage3Callbacks;
 
 impl Stage3Callbacks for DefaultStage3Callbacks {}
 
+/// The actors `stage3` sends to, resolved from the DSL graph at startup.
+pub struct Stage3Downstreams {
+    pub sink: ActorRef<SinkMessage>,
+}
+
+/// Arguments passed to `Stage3::spawn`. Carries the resolved downstream
+/// `ActorRef`s.
+pub struct Stage3Args {
+    pub downstreams: Stage3Downstreams,
+}
+
 #[allow(dead_code)]
 pub struct Stage3State {
     callbacks: Box<dyn Stage3Callbacks + Send + Sync>,
     send_count: usize,
+    downstreams: Stage3Downstreams,
 }
 
 #[derive(Debug, Clone)]
@@ -29,17 +42,18 @@ pub struct Stage3;
 impl Actor for Stage3 {
     type Msg = Stage3Message;
     type State = Stage3State;
-    type Arguments = ();
+    type Arguments = Stage3Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Stage3State {
             callbacks: Box::new(DefaultStage3Callbacks),
             send_count: 0,
+            downstreams: args.downstreams,
         };
 
         Ok(state)
@@ -54,7 +68,8 @@ impl Actor for Stage3 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Stage3Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
+                let _ = state.downstreams.sink.send_message(SinkMessage::Ping);
             }
         }
         Ok(())
@@ -0,0 +1,6 @@
+// Shared with `ractor_pubsub`/`ractor_loadbalanced` — see
+// `examples/common/clock.rs` for the implementation. This file just
+// re-exports it so `crate::clock::...` keeps working unchanged.
+#[path = "../../common/clock.rs"]
+mod shared;
+pub use shared::*;
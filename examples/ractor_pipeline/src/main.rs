@@ -2,28 +2,116 @@
 // Main entry point for pipeline_actors
 
 use pipeline_actors::actors::sink::Sink;
-use pipeline_actors::actors::source::Source;
-use pipeline_actors::actors::stage1::Stage1;
-use pipeline_actors::actors::stage2::Stage2;
-use pipeline_actors::actors::stage3::Stage3;
+use pipeline_actors::actors::source::{DefaultSourceCallbacks, Source, SourceArgs, SourceDownstreams};
+use pipeline_actors::retry::RetryPolicy;
+use pipeline_actors::actors::stage1::{Stage1, Stage1Args, Stage1Downstreams};
+use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
+use pipeline_actors::clock::{RealTimeDriver, VirtualClock};
+use pipeline_actors::supervisor::{
+    PipelineSupervisor, PipelineSupervisorArgs, RestartPolicy, RestartStrategy,
+    DefaultSupervisorCallbacks,
+};
 use ractor::Actor;
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("Starting actor system...");
+/// Spawns every actor in the DSL-described graph and resolves the declared
+/// edges into each actor's `Arguments`, so the pipeline is actually wired
+/// rather than five isolated processes.
+struct System;
+
+impl System {
+    async fn build() -> Result<(), Box<dyn std::error::Error>> {
+        println!("Starting actor system...");
+
+        let clock = VirtualClock::new();
+
+        // Spawn in reverse-topological order so each actor's ActorRef is
+        // available to wire into whatever sends to it.
+        let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ()).await?;
+
+        let (stage3_ref, _stage3_handle) = Stage3::spawn(
+            None,
+            Stage3,
+            Stage3Args {
+                downstreams: Stage3Downstreams { sink: sink_ref },
+            },
+        )
+        .await?;
+
+        let (stage2_ref, _stage2_handle) = Stage2::spawn(
+            None,
+            Stage2,
+            Stage2Args {
+                downstreams: Stage2Downstreams { stage3: stage3_ref },
+            },
+        )
+        .await?;
 
-    // Spawn all actors
-    let (_sink_ref, _sink_handle) = Sink::spawn(None, Sink, ()).await?;
-    let (_source_ref, _source_handle) = Source::spawn(None, Source, ()).await?;
-    let (_stage1_ref, _stage1_handle) = Stage1::spawn(None, Stage1, ()).await?;
-    let (_stage2_ref, _stage2_handle) = Stage2::spawn(None, Stage2, ()).await?;
-    let (_stage3_ref, _stage3_handle) = Stage3::spawn(None, Stage3, ()).await?;
+        let (stage1_ref, _stage1_handle) = Stage1::spawn(
+            None,
+            Stage1,
+            Stage1Args {
+                downstreams: Stage1Downstreams { stage2: stage2_ref },
+            },
+        )
+        .await?;
 
-    println!("Actor system started. Press Ctrl+C to exit.");
+        // `source` is the actor the DSL marks as supervised: spawn it under
+        // a `PipelineSupervisor` instead of spawning it directly, so a
+        // panic restarts it (re-registering its clock-driven timer) rather
+        // than silently breaking the pipeline.
+        let source_clock = clock.clone();
+        let (_supervisor_ref, _supervisor_handle) = PipelineSupervisor::spawn(
+            None,
+            PipelineSupervisor,
+            PipelineSupervisorArgs {
+                strategy: RestartStrategy::OneForOne,
+                policy: RestartPolicy::default(),
+                callbacks: Box::new(DefaultSupervisorCallbacks),
+                children: vec![(
+                    "source".to_string(),
+                    Box::new(move |supervisor_cell| {
+                        let clock = source_clock.clone();
+                        let stage1 = stage1_ref.clone();
+                        Box::pin(async move {
+                            let (source_ref, _source_handle) = Source::spawn_linked(
+                                None,
+                                Source,
+                                SourceArgs {
+                                    clock,
+                                    downstreams: SourceDownstreams { stage1 },
+                                    retry_policy: RetryPolicy::default(),
+                                    callbacks: Box::new(DefaultSourceCallbacks),
+                                },
+                                supervisor_cell,
+                            )
+                            .await?;
+                            Ok(source_ref.get_cell())
+                        })
+                    }),
+                )],
+            },
+        )
+        .await?;
 
-    // Keep running
-    tokio::signal::ctrl_c().await?;
-    println!("Shutting down...");
+        // Drive the shared clock in wall-clock time so the timer-based
+        // source above actually fires.
+        let driver_clock = clock.clone();
+        tokio::spawn(async move {
+            RealTimeDriver::new(driver_clock).run().await;
+        });
 
-    Ok(())
+        println!("Actor system started. Press Ctrl+C to exit.");
+
+        // Keep running
+        tokio::signal::ctrl_c().await?;
+        println!("Shutting down...");
+
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    System::build().await
 }
@@ -0,0 +1,6 @@
+// Shared with `ractor_loadbalanced` — see `examples/common/rate_limiter.rs`
+// for the implementation. This file just re-exports it so
+// `crate::rate_limiter::...` keeps working unchanged.
+#[path = "../../common/rate_limiter.rs"]
+mod shared;
+pub use shared::*;
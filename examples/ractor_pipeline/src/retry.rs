@@ -0,0 +1,6 @@
+// Shared with `ractor_pubsub` — see `examples/common/retry.rs` for the
+// implementation. This file just re-exports it so `crate::retry::...`
+// keeps working unchanged.
+#[path = "../../common/retry.rs"]
+mod shared;
+pub use shared::*;
@@ -0,0 +1,13 @@
+// Shared with `ractor_loadbalanced` — see `examples/common/supervisor.rs`
+// for the implementation. This file just re-exports it, aliasing the
+// generic `Supervisor` types to this crate's names so
+// `crate::supervisor::...` keeps working unchanged.
+#[path = "../../common/supervisor.rs"]
+mod shared;
+pub use shared::{
+    DefaultSupervisorCallbacks, RespawnFn, RestartPolicy, RestartStrategy,
+    SupervisionLifecycleEvent, SupervisorCallbacks,
+};
+pub use shared::Supervisor as PipelineSupervisor;
+pub use shared::SupervisorArgs as PipelineSupervisorArgs;
+pub use shared::SupervisorState as PipelineSupervisorState;
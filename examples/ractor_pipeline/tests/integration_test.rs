@@ -11,12 +11,60 @@ async fn test_actor_system() {
 
 #[tokio::test]
 async fn test_source_spawns() {
-    use pipeline_actors::actors::source::Source;
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::source::{
+        DefaultSourceCallbacks, Source, SourceArgs, SourceDownstreams,
+    };
+    use pipeline_actors::actors::stage1::{Stage1, Stage1Args, Stage1Downstreams};
+    use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
+    use pipeline_actors::clock::VirtualClock;
+    use pipeline_actors::retry::RetryPolicy;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Source::spawn(None, Source, ())
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
         .await
-        .expect("Failed to spawn source");
+        .expect("Failed to spawn sink");
+    let (stage3_ref, _stage3_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
+    let (stage2_ref, _stage2_handle) = Stage2::spawn(
+        None,
+        Stage2,
+        Stage2Args {
+            downstreams: Stage2Downstreams { stage3: stage3_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage2");
+    let (stage1_ref, _stage1_handle) = Stage1::spawn(
+        None,
+        Stage1,
+        Stage1Args {
+            downstreams: Stage1Downstreams { stage2: stage2_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage1");
+
+    let (actor_ref, actor_handle) = Source::spawn(
+        None,
+        Source,
+        SourceArgs {
+            clock: VirtualClock::new(),
+            downstreams: SourceDownstreams { stage1: stage1_ref },
+            retry_policy: RetryPolicy::default(),
+            callbacks: Box::new(DefaultSourceCallbacks),
+        },
+    )
+    .await
+    .expect("Failed to spawn source");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -29,15 +77,254 @@ async fn test_source_spawns() {
     let _ = actor_handle.await;
 }
 
+#[tokio::test]
+async fn test_source_retries_on_failure_then_gives_up() {
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::source::{Source, SourceArgs, SourceCallbacks, SourceDownstreams};
+    use pipeline_actors::actors::stage1::{Stage1, Stage1Args, Stage1Downstreams};
+    use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
+    use pipeline_actors::clock::{VirtualClock, VirtualDriver};
+    use pipeline_actors::retry::{Backoff, RetryPolicy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct AlwaysFailsCallbacks {
+        attempts: Arc<AtomicUsize>,
+        failed: Arc<AtomicUsize>,
+    }
+
+    impl SourceCallbacks for AlwaysFailsCallbacks {
+        fn on_data(&self) -> Result<(), String> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err("boom".to_string())
+        }
+
+        fn on_failed(&self) {
+            self.failed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
+        .await
+        .expect("Failed to spawn sink");
+    let (stage3_ref, _stage3_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
+    let (stage2_ref, _stage2_handle) = Stage2::spawn(
+        None,
+        Stage2,
+        Stage2Args {
+            downstreams: Stage2Downstreams { stage3: stage3_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage2");
+    let (stage1_ref, _stage1_handle) = Stage1::spawn(
+        None,
+        Stage1,
+        Stage1Args {
+            downstreams: Stage1Downstreams { stage2: stage2_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage1");
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let failed = Arc::new(AtomicUsize::new(0));
+    let clock = VirtualClock::new();
+
+    let (actor_ref, actor_handle) = Source::spawn(
+        None,
+        Source,
+        SourceArgs {
+            clock: clock.clone(),
+            downstreams: SourceDownstreams { stage1: stage1_ref },
+            retry_policy: RetryPolicy {
+                max_retries: 1,
+                backoff: Backoff::Fixed(Duration::from_millis(25)),
+            },
+            callbacks: Box::new(AlwaysFailsCallbacks {
+                attempts: attempts.clone(),
+                failed: failed.clone(),
+            }),
+        },
+    )
+    .await
+    .expect("Failed to spawn source");
+
+    // Drive the virtual clock by hand, interleaved with real yields so the
+    // actor actually processes each message before the next tick fires.
+    // The periodic 20ms tick and the 25ms retry backoff overlap: a second
+    // periodic tick lands at t=40, squarely inside the 20-45 backoff
+    // window for the first retry. If that tick weren't gated while the
+    // retry is outstanding, it would sneak in a third `on_data` call and
+    // this test would fail.
+    let driver = VirtualDriver::new(clock.clone());
+
+    driver.run_until(Duration::from_millis(20));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    driver.run_until(Duration::from_millis(40));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    driver.run_until(Duration::from_millis(45));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(
+        attempts.load(Ordering::SeqCst),
+        2,
+        "expected exactly one retry (the gated periodic tick at t=40 must not sneak in a third attempt)"
+    );
+    assert_eq!(failed.load(Ordering::SeqCst), 1);
+
+    // Clean up
+    actor_ref.stop(None);
+    let _ = actor_handle.await;
+}
+
+#[tokio::test]
+async fn test_source_reports_send_and_dropped_counts_via_metrics() {
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::source::{Source, SourceArgs, SourceCallbacks, SourceDownstreams};
+    use pipeline_actors::actors::stage1::{Stage1, Stage1Args, Stage1Downstreams};
+    use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
+    use pipeline_actors::clock::{VirtualClock, VirtualDriver};
+    use pipeline_actors::retry::RetryPolicy;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct ObservingCallbacks {
+        last_send_count: Arc<AtomicUsize>,
+        last_dropped_count: Arc<AtomicUsize>,
+    }
+
+    impl SourceCallbacks for ObservingCallbacks {
+        fn on_data(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_metrics(&self, send_count: usize, dropped_count: usize) {
+            self.last_send_count.store(send_count, Ordering::SeqCst);
+            self.last_dropped_count
+                .store(dropped_count, Ordering::SeqCst);
+        }
+    }
+
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
+        .await
+        .expect("Failed to spawn sink");
+    let (stage3_ref, _stage3_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
+    let (stage2_ref, _stage2_handle) = Stage2::spawn(
+        None,
+        Stage2,
+        Stage2Args {
+            downstreams: Stage2Downstreams { stage3: stage3_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage2");
+    let (stage1_ref, _stage1_handle) = Stage1::spawn(
+        None,
+        Stage1,
+        Stage1Args {
+            downstreams: Stage1Downstreams { stage2: stage2_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage1");
+
+    let last_send_count = Arc::new(AtomicUsize::new(0));
+    let last_dropped_count = Arc::new(AtomicUsize::new(0));
+    let clock = VirtualClock::new();
+
+    let (actor_ref, actor_handle) = Source::spawn(
+        None,
+        Source,
+        SourceArgs {
+            clock: clock.clone(),
+            downstreams: SourceDownstreams { stage1: stage1_ref },
+            retry_policy: RetryPolicy::default(),
+            callbacks: Box::new(ObservingCallbacks {
+                last_send_count: last_send_count.clone(),
+                last_dropped_count: last_dropped_count.clone(),
+            }),
+        },
+    )
+    .await
+    .expect("Failed to spawn source");
+
+    // One tick within the 50-token bucket, so it should be sent rather
+    // than dropped, and `on_metrics` should reflect that immediately.
+    let driver = VirtualDriver::new(clock.clone());
+    driver.run_until(Duration::from_millis(20));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(last_send_count.load(Ordering::SeqCst), 1);
+    assert_eq!(last_dropped_count.load(Ordering::SeqCst), 0);
+
+    // Clean up
+    actor_ref.stop(None);
+    let _ = actor_handle.await;
+}
+
 
 #[tokio::test]
 async fn test_stage1_spawns() {
-    use pipeline_actors::actors::stage1::Stage1;
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::stage1::{Stage1, Stage1Args, Stage1Downstreams};
+    use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Stage1::spawn(None, Stage1, ())
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
         .await
-        .expect("Failed to spawn stage1");
+        .expect("Failed to spawn sink");
+    let (stage3_ref, _stage3_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
+    let (stage2_ref, _stage2_handle) = Stage2::spawn(
+        None,
+        Stage2,
+        Stage2Args {
+            downstreams: Stage2Downstreams { stage3: stage3_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage2");
+
+    let (actor_ref, actor_handle) = Stage1::spawn(
+        None,
+        Stage1,
+        Stage1Args {
+            downstreams: Stage1Downstreams { stage2: stage2_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage1");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -53,12 +340,33 @@ async fn test_stage1_spawns() {
 
 #[tokio::test]
 async fn test_stage2_spawns() {
-    use pipeline_actors::actors::stage2::Stage2;
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::stage2::{Stage2, Stage2Args, Stage2Downstreams};
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Stage2::spawn(None, Stage2, ())
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
         .await
-        .expect("Failed to spawn stage2");
+        .expect("Failed to spawn sink");
+    let (stage3_ref, _stage3_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
+
+    let (actor_ref, actor_handle) = Stage2::spawn(
+        None,
+        Stage2,
+        Stage2Args {
+            downstreams: Stage2Downstreams { stage3: stage3_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage2");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -74,12 +382,23 @@ async fn test_stage2_spawns() {
 
 #[tokio::test]
 async fn test_stage3_spawns() {
-    use pipeline_actors::actors::stage3::Stage3;
+    use pipeline_actors::actors::sink::Sink;
+    use pipeline_actors::actors::stage3::{Stage3, Stage3Args, Stage3Downstreams};
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Stage3::spawn(None, Stage3, ())
+    let (sink_ref, _sink_handle) = Sink::spawn(None, Sink, ())
         .await
-        .expect("Failed to spawn stage3");
+        .expect("Failed to spawn sink");
+
+    let (actor_ref, actor_handle) = Stage3::spawn(
+        None,
+        Stage3,
+        Stage3Args {
+            downstreams: Stage3Downstreams { sink: sink_ref },
+        },
+    )
+    .await
+    .expect("Failed to spawn stage3");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -93,6 +412,277 @@ async fn test_stage3_spawns() {
 }
 
 
+#[tokio::test]
+async fn test_supervisor_restarts_child_on_repeated_panics() {
+    use pipeline_actors::supervisor::{
+        DefaultSupervisorCallbacks, PipelineSupervisor, PipelineSupervisorArgs, RestartPolicy,
+        RestartStrategy,
+    };
+    use ractor::{Actor, ActorProcessingErr, ActorRef};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    struct Flaky;
+
+    #[derive(Debug, Clone)]
+    enum FlakyMessage {
+        Panic,
+    }
+
+    impl Actor for Flaky {
+        type Msg = FlakyMessage;
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            match message {
+                FlakyMessage::Panic => Err("boom".into()),
+            }
+        }
+    }
+
+    let spawn_count = Arc::new(AtomicUsize::new(0));
+    let latest_ref: Arc<Mutex<Option<ActorRef<FlakyMessage>>>> = Arc::new(Mutex::new(None));
+    let spawn_count_for_respawn = spawn_count.clone();
+    let latest_ref_for_respawn = latest_ref.clone();
+
+    let (_supervisor_ref, _supervisor_handle) = PipelineSupervisor::spawn(
+        None,
+        PipelineSupervisor,
+        PipelineSupervisorArgs {
+            strategy: RestartStrategy::OneForOne,
+            policy: RestartPolicy {
+                max_restarts: 5,
+                window: std::time::Duration::from_secs(5),
+                backoff: std::time::Duration::from_millis(1),
+            },
+            callbacks: Box::new(DefaultSupervisorCallbacks),
+            children: vec![(
+                "flaky".to_string(),
+                Box::new(move |supervisor_cell| {
+                    let spawn_count = spawn_count_for_respawn.clone();
+                    let latest_ref = latest_ref_for_respawn.clone();
+                    Box::pin(async move {
+                        spawn_count.fetch_add(1, Ordering::SeqCst);
+                        let (flaky_ref, _flaky_handle) =
+                            Flaky::spawn_linked(None, Flaky, (), supervisor_cell).await?;
+                        *latest_ref.lock().unwrap() = Some(flaky_ref.clone());
+                        Ok(flaky_ref.get_cell())
+                    })
+                }),
+            )],
+        },
+    )
+    .await
+    .expect("Failed to spawn supervisor");
+
+    assert_eq!(spawn_count.load(Ordering::SeqCst), 1);
+
+    // First panic: the supervisor should observe the failure and respawn.
+    let first_ref = latest_ref.lock().unwrap().clone().unwrap();
+    first_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        spawn_count.load(Ordering::SeqCst),
+        2,
+        "first panic should trigger a restart"
+    );
+
+    // Second panic, on the *new* child: if the restarted child had been
+    // linked to the dead original cell instead of the supervisor's own
+    // cell, this failure would never be observed and spawn_count would
+    // stay at 2.
+    let second_ref = latest_ref.lock().unwrap().clone().unwrap();
+    second_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    assert_eq!(
+        spawn_count.load(Ordering::SeqCst),
+        3,
+        "second panic should also trigger a restart"
+    );
+}
+
+#[tokio::test]
+async fn test_one_for_all_restarts_and_stops_healthy_siblings() {
+    use pipeline_actors::supervisor::{
+        DefaultSupervisorCallbacks, PipelineSupervisor, PipelineSupervisorArgs, RestartPolicy,
+        RestartStrategy,
+    };
+    use ractor::{Actor, ActorProcessingErr, ActorRef, ActorStatus};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    struct Flaky;
+
+    #[derive(Debug, Clone)]
+    enum FlakyMessage {
+        Panic,
+    }
+
+    impl Actor for Flaky {
+        type Msg = FlakyMessage;
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            match message {
+                FlakyMessage::Panic => Err("boom".into()),
+            }
+        }
+    }
+
+    struct Healthy;
+
+    impl Actor for Healthy {
+        type Msg = ();
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            Ok(())
+        }
+    }
+
+    let flaky_spawn_count = Arc::new(AtomicUsize::new(0));
+    let flaky_latest_ref: Arc<Mutex<Option<ActorRef<FlakyMessage>>>> = Arc::new(Mutex::new(None));
+    let healthy_spawn_count = Arc::new(AtomicUsize::new(0));
+    let healthy_latest_ref: Arc<Mutex<Option<ActorRef<()>>>> = Arc::new(Mutex::new(None));
+
+    let flaky_spawn_count_for_respawn = flaky_spawn_count.clone();
+    let flaky_latest_ref_for_respawn = flaky_latest_ref.clone();
+    let healthy_spawn_count_for_respawn = healthy_spawn_count.clone();
+    let healthy_latest_ref_for_respawn = healthy_latest_ref.clone();
+
+    let (_supervisor_ref, _supervisor_handle) = PipelineSupervisor::spawn(
+        None,
+        PipelineSupervisor,
+        PipelineSupervisorArgs {
+            strategy: RestartStrategy::OneForAll,
+            policy: RestartPolicy {
+                max_restarts: 5,
+                window: std::time::Duration::from_secs(5),
+                backoff: std::time::Duration::from_millis(1),
+            },
+            callbacks: Box::new(DefaultSupervisorCallbacks),
+            children: vec![
+                (
+                    "flaky".to_string(),
+                    Box::new(move |supervisor_cell| {
+                        let spawn_count = flaky_spawn_count_for_respawn.clone();
+                        let latest_ref = flaky_latest_ref_for_respawn.clone();
+                        Box::pin(async move {
+                            spawn_count.fetch_add(1, Ordering::SeqCst);
+                            let (flaky_ref, _flaky_handle) =
+                                Flaky::spawn_linked(None, Flaky, (), supervisor_cell).await?;
+                            *latest_ref.lock().unwrap() = Some(flaky_ref.clone());
+                            Ok(flaky_ref.get_cell())
+                        })
+                    }),
+                ),
+                (
+                    "healthy".to_string(),
+                    Box::new(move |supervisor_cell| {
+                        let spawn_count = healthy_spawn_count_for_respawn.clone();
+                        let latest_ref = healthy_latest_ref_for_respawn.clone();
+                        Box::pin(async move {
+                            spawn_count.fetch_add(1, Ordering::SeqCst);
+                            let (healthy_ref, _healthy_handle) =
+                                Healthy::spawn_linked(None, Healthy, (), supervisor_cell).await?;
+                            *latest_ref.lock().unwrap() = Some(healthy_ref.clone());
+                            Ok(healthy_ref.get_cell())
+                        })
+                    }),
+                ),
+            ],
+        },
+    )
+    .await
+    .expect("Failed to spawn supervisor");
+
+    assert_eq!(flaky_spawn_count.load(Ordering::SeqCst), 1);
+    assert_eq!(healthy_spawn_count.load(Ordering::SeqCst), 1);
+
+    let original_healthy_ref = healthy_latest_ref.lock().unwrap().clone().unwrap();
+    let flaky_ref = flaky_latest_ref.lock().unwrap().clone().unwrap();
+    flaky_ref.send_message(FlakyMessage::Panic).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // OneForAll restarts every child, not just the one that failed.
+    assert_eq!(
+        flaky_spawn_count.load(Ordering::SeqCst),
+        2,
+        "the failed child should be restarted"
+    );
+    assert_eq!(
+        healthy_spawn_count.load(Ordering::SeqCst),
+        2,
+        "the healthy sibling should also be restarted under OneForAll"
+    );
+
+    // The original healthy instance must actually be stopped, not left
+    // running alongside its replacement as a leaked duplicate.
+    assert_eq!(original_healthy_ref.get_status(), ActorStatus::Stopped);
+}
+
+#[test]
+fn test_token_bucket_drops_once_exhausted() {
+    use pipeline_actors::rate_limiter::TokenBucket;
+    use std::time::Duration;
+
+    // Matches `Source`'s bucket: capacity 50, no time elapsed between
+    // sends so it never refills mid-burst.
+    let mut bucket = TokenBucket::new(50, 50.0);
+    let now = Duration::ZERO;
+
+    for _ in 0..50 {
+        assert!(bucket.try_consume(now), "capacity should not be exhausted yet");
+    }
+
+    // The 51st send in the same instant has no token available — this is
+    // exactly the condition under which `Source::handle` takes the
+    // `dropped_count += 1` branch instead of forwarding downstream.
+    assert!(!bucket.try_consume(now), "send past capacity should be dropped");
+}
+
 #[tokio::test]
 async fn test_sink_spawns() {
     use pipeline_actors::actors::sink::Sink;
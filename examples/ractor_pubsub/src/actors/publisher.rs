@@ -1,14 +1,25 @@
 // Generated from ActorSimulation DSL
 // Actor: publisher
 
+use crate::clock::VirtualClock;
+use crate::dataspace::{DataspaceMessage, EVENTS_TOPIC};
+use crate::retry::RetryPolicy;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::interval;
 
 /// PublisherCallbacks defines the callback trait
 /// Implement this trait to customize actor behavior
 pub trait PublisherCallbacks: Send + Sync {
-    fn on_event(&self);
+    /// `Event` is retryable: an `Err` causes the message to be re-enqueued
+    /// through the clock per `RetryPolicy`, instead of being dropped.
+    fn on_event(&self) -> Result<(), String>;
+
+    /// Called once `RetryPolicy::max_retries` is exhausted for a message.
+    fn on_failed(&self) {
+        println!("Publisher: giving up on event message after exhausting retries");
+    }
 }
 
 /// DefaultPublisherCallbacks provides default implementations
@@ -16,16 +27,37 @@ pub trait PublisherCallbacks: Send + Sync {
 pub struct DefaultPublisherCallbacks;
 
 impl PublisherCallbacks for DefaultPublisherCallbacks {
-    fn on_event(&self) {
+    fn on_event(&self) -> Result<(), String> {
         // TODO: Implement custom behavior for event
         println!("Publisher: Sending event message");
+        Ok(())
     }
 }
 
+/// Arguments passed to `Publisher::spawn`. Carries the shared clock handle
+/// so the periodic send is scheduled in simulated time instead of spawning
+/// a free-running timer of its own, plus the dataspace events are published
+/// into.
+pub struct PublisherArgs {
+    pub clock: VirtualClock,
+    pub dataspace: ActorRef<DataspaceMessage>,
+    pub retry_policy: RetryPolicy,
+    pub callbacks: Box<dyn PublisherCallbacks + Send + Sync>,
+}
+
 #[allow(dead_code)]
 pub struct PublisherState {
     callbacks: Box<dyn PublisherCallbacks + Send + Sync>,
     send_count: usize,
+    dataspace: ActorRef<DataspaceMessage>,
+    clock: VirtualClock,
+    attempts: usize,
+    retry_policy: RetryPolicy,
+    // Set while a retried `Event` message is outstanding, so the periodic
+    // 100ms schedule below skips sending a fresh one in the meantime —
+    // otherwise an unrelated tick would race the pending retry and bump or
+    // reset `attempts` for a message that isn't the one backing off.
+    retry_pending: Arc<AtomicBool>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,45 +70,71 @@ pub struct Publisher;
 impl Actor for Publisher {
     type Msg = PublisherMessage;
     type State = PublisherState;
-    type Arguments = ();
+    type Arguments = PublisherArgs;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
+        let retry_pending = Arc::new(AtomicBool::new(false));
         let state = PublisherState {
-            callbacks: Box::new(DefaultPublisherCallbacks),
+            callbacks: args.callbacks,
             send_count: 0,
+            dataspace: args.dataspace,
+            clock: args.clock.clone(),
+            attempts: 0,
+            retry_policy: args.retry_policy,
+            retry_pending: retry_pending.clone(),
         };
 
-        // Spawn periodic timer
+        // Register the periodic send with the shared clock instead of
+        // spawning a free-running `tokio::time::interval`. Skips a tick
+        // while a retry is outstanding, so it can't race a
+        // still-backing-off message.
         let actor_ref = myself.clone();
-        tokio::spawn(async move {
-            let mut interval = interval(Duration::from_millis(100));
-            loop {
-                interval.tick().await;
-                let _ = actor_ref.send_message(Self::Msg::Event);
-            }
-        });
+        args.clock
+            .schedule_repeating(Duration::from_millis(100), move || {
+                if !retry_pending.load(Ordering::SeqCst) {
+                    let _ = actor_ref.send_message(Self::Msg::Event);
+                }
+            });
         Ok(state)
     }
 
     #[allow(unused_variables)]
     async fn handle(
         &self,
-        _myself: ActorRef<Self::Msg>,
+        myself: ActorRef<Self::Msg>,
         message: Self::Msg,
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
-            PublisherMessage::Event => {
-                state.callbacks.on_event();
-                state.send_count += 1;
-                // Note: To send to other actors, you would need their ActorRef.
-                // Add target ActorRefs to the state in your custom implementation.
-            }
+            PublisherMessage::Event => match state.callbacks.on_event() {
+                Ok(()) => {
+                    state.attempts = 0;
+                    state.retry_pending.store(false, Ordering::SeqCst);
+                    state.send_count += 1;
+                    let _ = state.dataspace.send_message(DataspaceMessage::Publish {
+                        topic: EVENTS_TOPIC.to_string(),
+                    });
+                }
+                Err(_) if state.attempts < state.retry_policy.max_retries => {
+                    state.retry_pending.store(true, Ordering::SeqCst);
+                    let delay = state.retry_policy.backoff.delay_for(state.attempts);
+                    state.attempts += 1;
+                    let actor_ref = myself.clone();
+                    state.clock.schedule_once(delay, move || {
+                        let _ = actor_ref.send_message(Self::Msg::Event);
+                    });
+                }
+                Err(_) => {
+                    state.attempts = 0;
+                    state.retry_pending.store(false, Ordering::SeqCst);
+                    state.callbacks.on_failed();
+                }
+            },
         }
         Ok(())
     }
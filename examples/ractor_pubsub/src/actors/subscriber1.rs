@@ -2,17 +2,26 @@
 // Actor: subscriber1
 // DO NOT EDIT - This file is auto-generated
 
+use crate::dataspace::{DataspaceMessage, Subscription, EVENTS_TOPIC};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Subscriber1Callbacks defines the callback trait
 /// Implement this trait to customize actor behavior
 pub trait Subscriber1Callbacks: Send + Sync {}
 
+/// Arguments passed to `Subscriber1::spawn`. Carries the dataspace to
+/// register a real subscription with in `pre_start`.
+pub struct Subscriber1Args {
+    pub dataspace: ActorRef<DataspaceMessage>,
+}
 
 #[allow(dead_code)]
 pub struct Subscriber1State {
     callbacks: Box<dyn Subscriber1Callbacks + Send + Sync>,
     send_count: usize,
+    // Kept so `post_stop` can unsubscribe from the same dataspace the
+    // subscription was registered with in `pre_start`.
+    dataspace: ActorRef<DataspaceMessage>,
 }
 
 #[derive(Debug, Clone)]
@@ -25,19 +34,31 @@ pub struct Subscriber1;
 impl Actor for Subscriber1 {
     type Msg = Subscriber1Message;
     type State = Subscriber1State;
-    type Arguments = ();
+    type Arguments = Subscriber1Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Subscriber1State {
             callbacks: Box::new(DefaultSubscriber1Callbacks),
             send_count: 0,
+            dataspace: args.dataspace.clone(),
         };
 
+        // Register a real subscription with the dataspace instead of
+        // sitting unconnected.
+        let id = myself.get_id();
+        let subscriber_ref = myself.clone();
+        let _ = args.dataspace.send_message(DataspaceMessage::Subscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id,
+            subscription: Subscription::new(move || {
+                subscriber_ref.send_message(Self::Msg::Ping).is_ok()
+            }),
+        });
         Ok(state)
     }
 
@@ -50,9 +71,24 @@ impl Actor for Subscriber1 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Subscriber1Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
             }
         }
         Ok(())
     }
+
+    #[allow(unused_variables)]
+    async fn post_stop(
+        &self,
+        myself: ActorRef<Self::Msg>,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        // Drop the subscription explicitly instead of relying on delivery
+        // failures to prune it eventually.
+        let _ = state.dataspace.send_message(DataspaceMessage::Unsubscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: myself.get_id(),
+        });
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 // Actor: subscriber2
 // DO NOT EDIT - This file is auto-generated
 
+use crate::dataspace::{DataspaceMessage, Subscription, EVENTS_TOPIC};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 use super::subscriber2_callbacks::DefaultSubscriber2Callbacks;
 
@@ -9,11 +10,19 @@ use super::subscriber2_callbacks::DefaultSubscriber2Callbacks;
 /// Implement this trait to customize actor behavior
 pub trait Subscriber2Callbacks: Send + Sync {}
 
+/// Arguments passed to `Subscriber2::spawn`. Carries the dataspace to
+/// register a real subscription with in `pre_start`.
+pub struct Subscriber2Args {
+    pub dataspace: ActorRef<DataspaceMessage>,
+}
 
 #[allow(dead_code)]
 pub struct Subscriber2State {
     callbacks: Box<dyn Subscriber2Callbacks + Send + Sync>,
     send_count: usize,
+    // Kept so `post_stop` can unsubscribe from the same dataspace the
+    // subscription was registered with in `pre_start`.
+    dataspace: ActorRef<DataspaceMessage>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,19 +35,31 @@ pub struct Subscriber2;
 impl Actor for Subscriber2 {
     type Msg = Subscriber2Message;
     type State = Subscriber2State;
-    type Arguments = ();
+    type Arguments = Subscriber2Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Subscriber2State {
             callbacks: Box::new(DefaultSubscriber2Callbacks),
             send_count: 0,
+            dataspace: args.dataspace.clone(),
         };
 
+        // Register a real subscription with the dataspace instead of
+        // sitting unconnected.
+        let id = myself.get_id();
+        let subscriber_ref = myself.clone();
+        let _ = args.dataspace.send_message(DataspaceMessage::Subscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id,
+            subscription: Subscription::new(move || {
+                subscriber_ref.send_message(Self::Msg::Ping).is_ok()
+            }),
+        });
         Ok(state)
     }
 
@@ -51,9 +72,24 @@ impl Actor for Subscriber2 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Subscriber2Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
             }
         }
         Ok(())
     }
+
+    #[allow(unused_variables)]
+    async fn post_stop(
+        &self,
+        myself: ActorRef<Self::Msg>,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        // Drop the subscription explicitly instead of relying on delivery
+        // failures to prune it eventually.
+        let _ = state.dataspace.send_message(DataspaceMessage::Unsubscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: myself.get_id(),
+        });
+        Ok(())
+    }
 }
@@ -2,17 +2,26 @@
 // Actor: subscriber3
 // DO NOT EDIT - This file is auto-generated
 
+use crate::dataspace::{DataspaceMessage, Subscription, EVENTS_TOPIC};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
 /// Subscriber3Callbacks defines the callback trait
 /// Implement this trait to customize actor behavior
 pub trait Subscriber3Callbacks: Send + Sync {}
 
+/// Arguments passed to `Subscriber3::spawn`. Carries the dataspace to
+/// register a real subscription with in `pre_start`.
+pub struct Subscriber3Args {
+    pub dataspace: ActorRef<DataspaceMessage>,
+}
 
 #[allow(dead_code)]
 pub struct Subscriber3State {
     callbacks: Box<dyn Subscriber3Callbacks + Send + Sync>,
     send_count: usize,
+    // Kept so `post_stop` can unsubscribe from the same dataspace the
+    // subscription was registered with in `pre_start`.
+    dataspace: ActorRef<DataspaceMessage>,
 }
 
 #[derive(Debug, Clone)]
@@ -25,19 +34,31 @@ pub struct Subscriber3;
 impl Actor for Subscriber3 {
     type Msg = Subscriber3Message;
     type State = Subscriber3State;
-    type Arguments = ();
+    type Arguments = Subscriber3Args;
 
     #[allow(unused_variables)]
     async fn pre_start(
         &self,
         myself: ActorRef<Self::Msg>,
-        _: Self::Arguments,
+        args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
         let state = Subscriber3State {
             callbacks: Box::new(DefaultSubscriber3Callbacks),
             send_count: 0,
+            dataspace: args.dataspace.clone(),
         };
 
+        // Register a real subscription with the dataspace instead of
+        // sitting unconnected.
+        let id = myself.get_id();
+        let subscriber_ref = myself.clone();
+        let _ = args.dataspace.send_message(DataspaceMessage::Subscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id,
+            subscription: Subscription::new(move || {
+                subscriber_ref.send_message(Self::Msg::Ping).is_ok()
+            }),
+        });
         Ok(state)
     }
 
@@ -50,9 +71,24 @@ impl Actor for Subscriber3 {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             Subscriber3Message::Ping => {
-                // Default message handler
+                state.send_count += 1;
             }
         }
         Ok(())
     }
+
+    #[allow(unused_variables)]
+    async fn post_stop(
+        &self,
+        myself: ActorRef<Self::Msg>,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        // Drop the subscription explicitly instead of relying on delivery
+        // failures to prune it eventually.
+        let _ = state.dataspace.send_message(DataspaceMessage::Unsubscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: myself.get_id(),
+        });
+        Ok(())
+    }
 }
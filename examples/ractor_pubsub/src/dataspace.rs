@@ -0,0 +1,107 @@
+// Generated from ActorSimulation DSL
+// Subsystem: dataspace
+//
+// `Publisher` emits `Event` on a timer and the `Subscriber`s react to
+// `Ping`, but nothing connects them. A `Dataspace` broker actor sits
+// between them: a subscriber `assert`s interest in a topic by registering
+// a delivery handle, the publisher sends each event into the dataspace
+// against a topic, and the broker fans it out to every subscriber of that
+// topic, pruning subscriptions whose delivery fails (the subscriber has
+// terminated). Subscribers' `Msg` types differ per DSL actor, so a
+// `Subscription`'s delivery is type-erased behind a closure instead of
+// storing a concrete `ActorRef<T>`, letting one `Dataspace` fan out to any
+// mix of subscriber types per topic.
+
+use ractor::{Actor, ActorId, ActorProcessingErr, ActorRef};
+use std::collections::HashMap;
+
+/// The default topic used by this example's publisher/subscriber wiring.
+/// Generated code would instead use whatever topic name the DSL graph's
+/// edge declares.
+pub const EVENTS_TOPIC: &str = "events";
+
+/// A type-erased handle for delivering an event to one subscriber,
+/// regardless of which concrete `Msg` type that subscriber's actor uses.
+/// `deliver` returns `false` once the subscriber has terminated, so the
+/// broker can prune it.
+pub struct Subscription {
+    deliver: Box<dyn Fn() -> bool + Send>,
+}
+
+impl Subscription {
+    pub fn new<F>(deliver: F) -> Self
+    where
+        F: Fn() -> bool + Send + 'static,
+    {
+        Self {
+            deliver: Box::new(deliver),
+        }
+    }
+}
+
+pub enum DataspaceMessage {
+    Subscribe {
+        topic: String,
+        id: ActorId,
+        subscription: Subscription,
+    },
+    Unsubscribe {
+        topic: String,
+        id: ActorId,
+    },
+    Publish {
+        topic: String,
+    },
+}
+
+#[allow(dead_code)]
+pub struct DataspaceState {
+    topics: HashMap<String, HashMap<ActorId, Subscription>>,
+}
+
+pub struct Dataspace;
+
+impl Actor for Dataspace {
+    type Msg = DataspaceMessage;
+    type State = DataspaceState;
+    type Arguments = ();
+
+    #[allow(unused_variables)]
+    async fn pre_start(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        _: Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        Ok(DataspaceState {
+            topics: HashMap::new(),
+        })
+    }
+
+    async fn handle(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        message: Self::Msg,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        match message {
+            DataspaceMessage::Subscribe {
+                topic,
+                id,
+                subscription,
+            } => {
+                state.topics.entry(topic).or_default().insert(id, subscription);
+            }
+            DataspaceMessage::Unsubscribe { topic, id } => {
+                if let Some(subscribers) = state.topics.get_mut(&topic) {
+                    subscribers.remove(&id);
+                }
+            }
+            DataspaceMessage::Publish { topic } => {
+                if let Some(subscribers) = state.topics.get_mut(&topic) {
+                    subscribers.retain(|_, subscription| (subscription.deliver)());
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,27 +1,68 @@
 // Generated from ActorSimulation DSL
 // Main entry point for pubsub_actors
 
-use pubsub_actors::actors::publisher::Publisher;
-use pubsub_actors::actors::subscriber1::Subscriber1;
-use pubsub_actors::actors::subscriber2::Subscriber2;
-use pubsub_actors::actors::subscriber3::Subscriber3;
+use pubsub_actors::actors::publisher::{DefaultPublisherCallbacks, Publisher, PublisherArgs};
+use pubsub_actors::actors::subscriber1::{Subscriber1, Subscriber1Args};
+use pubsub_actors::actors::subscriber2::{Subscriber2, Subscriber2Args};
+use pubsub_actors::actors::subscriber3::{Subscriber3, Subscriber3Args};
+use pubsub_actors::clock::{RealTimeDriver, VirtualClock};
+use pubsub_actors::dataspace::Dataspace;
+use pubsub_actors::retry::RetryPolicy;
 use ractor::Actor;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Starting actor system...");
 
+    let clock = VirtualClock::new();
+
     // Spawn all actors
-    let (_publisher_ref, _publisher_handle) = Publisher::spawn(None, Publisher, ()).await?;
-    let (_subscriber1_ref, _subscriber1_handle) =
-        Subscriber1::spawn(None, Subscriber1, ()).await?;
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ()).await?;
+
+    let (_publisher_ref, _publisher_handle) = Publisher::spawn(
+        None,
+        Publisher,
+        PublisherArgs {
+            clock: clock.clone(),
+            dataspace: dataspace_ref.clone(),
+            retry_policy: RetryPolicy::default(),
+            callbacks: Box::new(DefaultPublisherCallbacks),
+        },
+    )
+    .await?;
+    let (_subscriber1_ref, _subscriber1_handle) = Subscriber1::spawn(
+        None,
+        Subscriber1,
+        Subscriber1Args {
+            dataspace: dataspace_ref.clone(),
+        },
+    )
+    .await?;
 
-    let (_subscriber2_ref, _subscriber2_handle) =
-        Subscriber2::spawn(None, Subscriber2, ()).await?;
+    let (_subscriber2_ref, _subscriber2_handle) = Subscriber2::spawn(
+        None,
+        Subscriber2,
+        Subscriber2Args {
+            dataspace: dataspace_ref.clone(),
+        },
+    )
+    .await?;
 
-    let (_subscriber3_ref, _subscriber3_handle) =
-        Subscriber3::spawn(None, Subscriber3, ()).await?;
+    let (_subscriber3_ref, _subscriber3_handle) = Subscriber3::spawn(
+        None,
+        Subscriber3,
+        Subscriber3Args {
+            dataspace: dataspace_ref,
+        },
+    )
+    .await?;
 
+    // Drive the shared clock in wall-clock time so the timer-based actors
+    // above actually fire.
+    let driver_clock = clock.clone();
+    tokio::spawn(async move {
+        RealTimeDriver::new(driver_clock).run().await;
+    });
 
     println!("Actor system started. Press Ctrl+C to exit.");
 
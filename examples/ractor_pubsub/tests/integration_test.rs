@@ -11,12 +11,28 @@ async fn test_actor_system() {
 
 #[tokio::test]
 async fn test_publisher_spawns() {
-    use pubsub_actors::actors::publisher::Publisher;
+    use pubsub_actors::actors::publisher::{DefaultPublisherCallbacks, Publisher, PublisherArgs};
+    use pubsub_actors::clock::VirtualClock;
+    use pubsub_actors::dataspace::Dataspace;
+    use pubsub_actors::retry::RetryPolicy;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Publisher::spawn(None, Publisher, ())
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
         .await
-        .expect("Failed to spawn publisher");
+        .expect("Failed to spawn dataspace");
+
+    let (actor_ref, actor_handle) = Publisher::spawn(
+        None,
+        Publisher,
+        PublisherArgs {
+            clock: VirtualClock::new(),
+            dataspace: dataspace_ref,
+            retry_policy: RetryPolicy::default(),
+            callbacks: Box::new(DefaultPublisherCallbacks),
+        },
+    )
+    .await
+    .expect("Failed to spawn publisher");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -29,15 +45,236 @@ async fn test_publisher_spawns() {
     let _ = actor_handle.await;
 }
 
+#[tokio::test]
+async fn test_publisher_retries_on_failure_then_gives_up() {
+    use pubsub_actors::actors::publisher::{Publisher, PublisherArgs, PublisherCallbacks};
+    use pubsub_actors::clock::{VirtualClock, VirtualDriver};
+    use pubsub_actors::dataspace::Dataspace;
+    use pubsub_actors::retry::{Backoff, RetryPolicy};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct AlwaysFailsCallbacks {
+        attempts: Arc<AtomicUsize>,
+        failed: Arc<AtomicUsize>,
+    }
+
+    impl PublisherCallbacks for AlwaysFailsCallbacks {
+        fn on_event(&self) -> Result<(), String> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err("boom".to_string())
+        }
+
+        fn on_failed(&self) {
+            self.failed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
+        .await
+        .expect("Failed to spawn dataspace");
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let failed = Arc::new(AtomicUsize::new(0));
+    let clock = VirtualClock::new();
+
+    let (actor_ref, actor_handle) = Publisher::spawn(
+        None,
+        Publisher,
+        PublisherArgs {
+            clock: clock.clone(),
+            dataspace: dataspace_ref,
+            retry_policy: RetryPolicy {
+                max_retries: 1,
+                backoff: Backoff::Fixed(Duration::from_millis(150)),
+            },
+            callbacks: Box::new(AlwaysFailsCallbacks {
+                attempts: attempts.clone(),
+                failed: failed.clone(),
+            }),
+        },
+    )
+    .await
+    .expect("Failed to spawn publisher");
+
+    // Drive the virtual clock by hand, interleaved with real yields so the
+    // actor actually processes each message before the next tick fires.
+    // The periodic 100ms tick and the 150ms retry backoff overlap: a
+    // second periodic tick lands at t=200, squarely inside the 100-250
+    // backoff window for the first retry. If that tick weren't gated while
+    // the retry is outstanding, it would sneak in a third `on_event` call
+    // and this test would fail.
+    let driver = VirtualDriver::new(clock.clone());
+
+    driver.run_until(Duration::from_millis(100));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    driver.run_until(Duration::from_millis(200));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    driver.run_until(Duration::from_millis(250));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(
+        attempts.load(Ordering::SeqCst),
+        2,
+        "expected exactly one retry (the gated periodic tick at t=200 must not sneak in a third attempt)"
+    );
+    assert_eq!(failed.load(Ordering::SeqCst), 1);
+
+    // Clean up
+    actor_ref.stop(None);
+    let _ = actor_handle.await;
+}
+
+
+#[tokio::test]
+async fn test_dataspace_delivers_publishes_and_prunes_terminated_subscribers() {
+    use pubsub_actors::dataspace::{Dataspace, DataspaceMessage, Subscription, EVENTS_TOPIC};
+    use ractor::{Actor, ActorProcessingErr, ActorRef};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // A no-op actor, spawned only so its `get_id()` gives the `Dataspace`'s
+    // `HashMap<ActorId, _>` a distinct, real key per subscriber — `ActorId`
+    // has no public constructor of its own.
+    struct Placeholder;
+
+    impl Actor for Placeholder {
+        type Msg = ();
+        type State = ();
+        type Arguments = ();
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _args: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(())
+        }
+
+        async fn handle(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            _message: Self::Msg,
+            _state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            Ok(())
+        }
+    }
+
+    let (dataspace_ref, dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
+        .await
+        .expect("Failed to spawn dataspace");
+
+    let (live_placeholder, live_placeholder_handle) =
+        Placeholder::spawn(None, Placeholder, ()).await.unwrap();
+    let live_id = live_placeholder.get_id();
+    let (terminated_placeholder, terminated_placeholder_handle) =
+        Placeholder::spawn(None, Placeholder, ()).await.unwrap();
+    let terminated_id = terminated_placeholder.get_id();
+
+    let alive_deliveries = Arc::new(AtomicUsize::new(0));
+    let alive_deliveries_for_closure = alive_deliveries.clone();
+    dataspace_ref
+        .send_message(DataspaceMessage::Subscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: live_id,
+            subscription: Subscription::new(move || {
+                alive_deliveries_for_closure.fetch_add(1, Ordering::SeqCst);
+                true
+            }),
+        })
+        .unwrap();
+
+    // A second subscriber whose delivery reports the subscriber has
+    // terminated, so the broker should prune it on the next publish.
+    dataspace_ref
+        .send_message(DataspaceMessage::Subscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: terminated_id,
+            subscription: Subscription::new(|| false),
+        })
+        .unwrap();
+
+    dataspace_ref
+        .send_message(DataspaceMessage::Publish {
+            topic: EVENTS_TOPIC.to_string(),
+        })
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    assert_eq!(
+        alive_deliveries.load(Ordering::SeqCst),
+        1,
+        "a live subscriber should receive the published event"
+    );
+
+    // Publishing again must not panic or error even though the second
+    // subscriber was pruned after its failed delivery above.
+    dataspace_ref
+        .send_message(DataspaceMessage::Publish {
+            topic: EVENTS_TOPIC.to_string(),
+        })
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    assert_eq!(
+        alive_deliveries.load(Ordering::SeqCst),
+        2,
+        "the live subscriber should still be delivered to after the prune"
+    );
+
+    // Unsubscribe the live subscriber and confirm it no longer receives
+    // publishes — exercising the `Unsubscribe` variant, which otherwise
+    // nothing in this crate ever sends.
+    dataspace_ref
+        .send_message(DataspaceMessage::Unsubscribe {
+            topic: EVENTS_TOPIC.to_string(),
+            id: live_id,
+        })
+        .unwrap();
+    dataspace_ref
+        .send_message(DataspaceMessage::Publish {
+            topic: EVENTS_TOPIC.to_string(),
+        })
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    assert_eq!(
+        alive_deliveries.load(Ordering::SeqCst),
+        2,
+        "an unsubscribed subscriber should no longer receive publishes"
+    );
+
+    live_placeholder.stop(None);
+    let _ = live_placeholder_handle.await;
+    terminated_placeholder.stop(None);
+    let _ = terminated_placeholder_handle.await;
+    dataspace_ref.stop(None);
+    let _ = dataspace_handle.await;
+}
 
 #[tokio::test]
 async fn test_subscriber1_spawns() {
-    use pubsub_actors::actors::subscriber1::Subscriber1;
+    use pubsub_actors::actors::subscriber1::{Subscriber1, Subscriber1Args};
+    use pubsub_actors::dataspace::Dataspace;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Subscriber1::spawn(None, Subscriber1, ())
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
         .await
-        .expect("Failed to spawn subscriber1");
+        .expect("Failed to spawn dataspace");
+
+    let (actor_ref, actor_handle) = Subscriber1::spawn(
+        None,
+        Subscriber1,
+        Subscriber1Args {
+            dataspace: dataspace_ref,
+        },
+    )
+    .await
+    .expect("Failed to spawn subscriber1");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -53,12 +290,23 @@ async fn test_subscriber1_spawns() {
 
 #[tokio::test]
 async fn test_subscriber2_spawns() {
-    use pubsub_actors::actors::subscriber2::Subscriber2;
+    use pubsub_actors::actors::subscriber2::{Subscriber2, Subscriber2Args};
+    use pubsub_actors::dataspace::Dataspace;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Subscriber2::spawn(None, Subscriber2, ())
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
         .await
-        .expect("Failed to spawn subscriber2");
+        .expect("Failed to spawn dataspace");
+
+    let (actor_ref, actor_handle) = Subscriber2::spawn(
+        None,
+        Subscriber2,
+        Subscriber2Args {
+            dataspace: dataspace_ref,
+        },
+    )
+    .await
+    .expect("Failed to spawn subscriber2");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -74,12 +322,23 @@ async fn test_subscriber2_spawns() {
 
 #[tokio::test]
 async fn test_subscriber3_spawns() {
-    use pubsub_actors::actors::subscriber3::Subscriber3;
+    use pubsub_actors::actors::subscriber3::{Subscriber3, Subscriber3Args};
+    use pubsub_actors::dataspace::Dataspace;
     use ractor::ActorStatus;
 
-    let (actor_ref, actor_handle) = Subscriber3::spawn(None, Subscriber3, ())
+    let (dataspace_ref, _dataspace_handle) = Dataspace::spawn(None, Dataspace, ())
         .await
-        .expect("Failed to spawn subscriber3");
+        .expect("Failed to spawn dataspace");
+
+    let (actor_ref, actor_handle) = Subscriber3::spawn(
+        None,
+        Subscriber3,
+        Subscriber3Args {
+            dataspace: dataspace_ref,
+        },
+    )
+    .await
+    .expect("Failed to spawn subscriber3");
 
     // Give it time to initialize
     tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;